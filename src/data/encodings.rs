@@ -1,6 +1,5 @@
 //! Some encodings for the `Data` type
 
-use crate::helpers::iter_ext::IterExt;
 use std::{ str, fmt::Debug, hash::Hasher };
 
 
@@ -8,6 +7,17 @@ use std::{ str, fmt::Debug, hash::Hasher };
 pub trait Encoding: Copy + Clone + Debug + Default {
 	/// Checks if `bytes` conform to the encoding
 	fn is_valid(bytes: &[u8]) -> bool;
+	/// Gets the position of the first byte that does not conform to the encoding or `None` if
+	/// `bytes` are valid
+	///
+	/// _Note: the default implementation cannot locate the error and reports position `0` for
+	/// invalid data; all encodings in this module provide the exact position._
+	fn first_invalid(bytes: &[u8]) -> Option<usize> {
+		match Self::is_valid(bytes) {
+			true => None,
+			false => Some(0)
+		}
+	}
 	/// Checks if `a` is equal to `b` (can be overridden; e.g. to perform a case-insensitive
 	/// comparison)
 	fn is_eq(a: &[u8], b: &[u8]) -> bool {
@@ -20,6 +30,9 @@ pub trait Encoding: Copy + Clone + Debug + Default {
 }
 /// Defines that `self` is a subset of `T`
 pub trait SubsetOf<T: Encoding>: Encoding {}
+/// Defines that `self` uses [percent-encoding](https://tools.ietf.org/html/rfc3986#section-2.1)
+/// to represent arbitrary bytes
+pub trait PercentEncoded: Encoding {}
 
 
 /// An encoding that allows all bytes
@@ -29,6 +42,9 @@ impl Encoding for Binary {
 	fn is_valid(_bytes: &[u8]) -> bool {
 		true
 	}
+	fn first_invalid(_bytes: &[u8]) -> Option<usize> {
+		None
+	}
 }
 
 
@@ -39,6 +55,9 @@ impl Encoding for Utf8 {
 	fn is_valid(bytes: &[u8]) -> bool {
 		str::from_utf8(bytes).is_ok()
 	}
+	fn first_invalid(bytes: &[u8]) -> Option<usize> {
+		str::from_utf8(bytes).err().map(|e| e.valid_up_to())
+	}
 }
 
 
@@ -58,12 +77,15 @@ impl Encoding for Utf8 {
 pub struct Ascii;
 impl Encoding for Ascii {
 	fn is_valid(bytes: &[u8]) -> bool {
+		Self::first_invalid(bytes).is_none()
+	}
+	fn first_invalid(bytes: &[u8]) -> Option<usize> {
 		fn is_printable(b: &u8) -> bool {
 			b.is_ascii_alphanumeric()
 				| b.is_ascii_whitespace()
 				| b.is_ascii_punctuation()
 		}
-		bytes.iter().all(is_printable)
+		bytes.iter().position(|b| !is_printable(b))
 	}
 }
 impl SubsetOf<Binary> for Ascii {}
@@ -82,26 +104,26 @@ impl SubsetOf<Utf8> for Ascii {}
 pub struct HeaderFieldKey;
 impl Encoding for HeaderFieldKey {
 	fn is_valid(bytes: &[u8]) -> bool {
+		Self::first_invalid(bytes).is_none()
+	}
+	fn first_invalid(bytes: &[u8]) -> Option<usize> {
 		// Key must not be empty
 		match bytes.len() {
-			0 => false,
-			_ => bytes.iter().all(|b| match *b {
+			0 => Some(0),
+			_ => bytes.iter().position(|b| match *b {
 				// Must be US-ASCII; control-chars or separators are invalid
-				b if b > 127 => false,
-				b if b < 32 => false,
-				b if b"()<>@,;:/[]?={} \t\"\\".contains(&b) => false,
-				_ => true
+				b if b > 127 => true,
+				b if b < 32 => true,
+				b if b"()<>@,;:/[]?={} \t\"\\".contains(&b) => true,
+				_ => false
 			})
 		}
 	}
 	fn is_eq(a: &[u8], b: &[u8]) -> bool {
-		let a= str::from_utf8(a).unwrap().to_ascii_lowercase();
-		let b= str::from_utf8(b).unwrap().to_ascii_lowercase();
-		a == b
+		a.eq_ignore_ascii_case(b)
 	}
 	fn hash(bytes: &[u8], hasher: &mut dyn Hasher) {
-		let s = str::from_utf8(bytes).unwrap().to_ascii_lowercase();
-		hasher.write(s.as_bytes());
+		hasher.write(&bytes.to_ascii_lowercase());
 	}
 }
 impl SubsetOf<Binary> for HeaderFieldKey {}
@@ -109,92 +131,151 @@ impl SubsetOf<Utf8> for HeaderFieldKey {}
 impl SubsetOf<Ascii> for HeaderFieldKey {}
 
 
+/// A token according to [RFC 7230](https://tools.ietf.org/html/rfc7230#section-3.2.6)
+///
+/// This ASCII mode includes all
+///  - alphabetic characters
+///  - digits
+///  - the punctuation characters ``! # $ % & ' * + - . ^ _ ` | ~``
+///
+/// _Note: in contrast to `HeaderFieldKey`, tokens are compared case-sensitively._
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Token;
+impl Encoding for Token {
+	fn is_valid(bytes: &[u8]) -> bool {
+		Self::first_invalid(bytes).is_none()
+	}
+	fn first_invalid(bytes: &[u8]) -> Option<usize> {
+		// Token must not be empty
+		match bytes.len() {
+			0 => Some(0),
+			_ => bytes.iter().position(|b| match *b {
+				b if b.is_ascii_alphanumeric() => false,
+				b => !b"!#$%&'*+-.^_`|~".contains(&b)
+			})
+		}
+	}
+}
+impl SubsetOf<Binary> for Token {}
+impl SubsetOf<Utf8> for Token {}
+impl SubsetOf<Ascii> for Token {}
+impl SubsetOf<FieldValue> for Token {}
+
+
+/// A header-field value according to [RFC 7230](https://tools.ietf.org/html/rfc7230#section-3.2)
+///
+/// This mode includes all
+///  - visible characters (U+0021 '!' ... U+007E '~')
+///  - whitespace characters (U+0020 SPACE, U+0009 HORIZONTAL TAB)
+///  - the obsolete `obs-text` (the bytes `0x80` ... `0xFF`)
+///
+/// _Note: in contrast to `Ascii`, line breaks are not allowed so that a value cannot inject
+/// additional header fields. Because of `obs-text`, a field value is not necessarily UTF-8 and is
+/// displayed as ISO-8859-1._
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FieldValue;
+impl Encoding for FieldValue {
+	fn is_valid(bytes: &[u8]) -> bool {
+		Self::first_invalid(bytes).is_none()
+	}
+	fn first_invalid(bytes: &[u8]) -> Option<usize> {
+		bytes.iter().position(|b| match *b {
+			b' ' | b'\t' | 0x80..=0xff => false,
+			b => !b.is_ascii_graphic()
+		})
+	}
+}
+impl SubsetOf<Binary> for FieldValue {}
+
+
 /// A valid URI according to [RFC 3986](https://tools.ietf.org/html/rfc3986)
 #[derive(Copy, Clone, Debug, Default)]
 pub struct Uri;
 impl Uri {
-	/// Checks if `b` and the next two chars contain a valid
+	/// Checks if `bytes` start with a valid
 	/// [percent encoding](https://tools.ietf.org/html/rfc3986#section-2.1)
-	fn percent_encoding(b: u8, next: &mut dyn Iterator<Item = &u8>) -> bool {
-		match b {
-			b'%' => {
-				// Take the next two bytes and ensure that they are hex digits
-				let ab = match next.take(2).collect_min(2) {
-					Some(ab) => ab,
-					None => return false
-				};
-				ab.iter().all(|b| b.is_ascii_hexdigit())
-			},
-			_ => return false
+	pub(crate) fn percent_encoding(bytes: &[u8]) -> bool {
+		match bytes {
+			[b'%', a, b, ..] => a.is_ascii_hexdigit() && b.is_ascii_hexdigit(),
+			_ => false
+		}
+	}
+	/// Gets the position of the first byte in `bytes` that is neither allowed by `is_allowed` nor
+	/// part of a valid percent encoding
+	pub(crate) fn first_invalid_with(bytes: &[u8], is_allowed: impl Fn(u8) -> bool)
+		-> Option<usize>
+	{
+		let mut pos = 0;
+		while pos < bytes.len() {
+			match bytes[pos] {
+				b if is_allowed(b) => pos += 1,
+				_ if Self::percent_encoding(&bytes[pos..]) => pos += 3,
+				_ => return Some(pos)
+			}
 		}
+		None
 	}
 	
 	/// Checks if `b` is one of the
 	/// [unreserved chars](https://tools.ietf.org/html/rfc3986#section-2.3)
-	fn unreserved(b: u8) -> bool {
+	pub(crate) fn unreserved(b: u8) -> bool {
 		b.is_ascii_alphanumeric() || b"-._~".contains(&b)
 	}
 	
 	/// Checks if `b` is one of the [`gen-delims`](https://tools.ietf.org/html/rfc3986#section-2.2)
-	fn gen_delims(b: u8) -> bool {
+	pub(crate) fn gen_delims(b: u8) -> bool {
 		b":/?#[]@".contains(&b)
 	}
 	/// Checks if `b` is one of the [`sub-delims`](https://tools.ietf.org/html/rfc3986#section-2.2)
-	fn sub_delims(b: u8) -> bool {
+	pub(crate) fn sub_delims(b: u8) -> bool {
 		b"!$&'()*+,;=".contains(&b)
 	}
 	/// Checks if `b` is one of the
 	/// [reserved chars](https://tools.ietf.org/html/rfc3986#section-2.2)
-	fn reserved(b: u8) -> bool {
+	pub(crate) fn reserved(b: u8) -> bool {
 		Self::gen_delims(b) || Self::sub_delims(b)
 	}
 }
 impl Encoding for Uri {
 	fn is_valid(bytes: &[u8]) -> bool {
+		Self::first_invalid(bytes).is_none()
+	}
+	fn first_invalid(bytes: &[u8]) -> Option<usize> {
 		// Validate that all characters are valid URI characters
-		let mut bytes = bytes.iter();
-		while let Some(b) = bytes.next() {
-			match *b {
-				b if Self::unreserved(b) => continue,
-				b if Self::reserved(b) => continue,
-				b => match Self::percent_encoding(b, &mut bytes) {
-					true => continue,
-					false => return false
-				}
-			}
-		}
-		true
+		Self::first_invalid_with(bytes, |b| Self::unreserved(b) || Self::reserved(b))
 	}
 }
 impl SubsetOf<Binary> for Uri {}
 impl SubsetOf<Utf8> for Uri {}
 impl SubsetOf<Ascii> for Uri {}
+impl PercentEncoded for Uri {}
 
 
 /// A valid query according to [RFC 3986](https://tools.ietf.org/html/rfc3986#section-3.4)
+///
+/// _Note: a [fragment](https://tools.ietf.org/html/rfc3986#section-3.5) has the same grammar and
+/// is thus represented using this encoding, too._
 #[derive(Copy, Clone, Debug, Default)]
 pub struct UriQuery;
 impl Encoding for UriQuery {
 	fn is_valid(bytes: &[u8]) -> bool {
-		// Validate that all characters are valid URI characters
-		let mut bytes = bytes.iter();
-		while let Some(b) = bytes.next() {
-			match *b {
-				b if Uri::unreserved(b) => continue,
-				b if Uri::sub_delims(b) => continue,
-				b':' | b'@' => continue,
-				b => match Uri::percent_encoding(b, &mut bytes) {
-					true => continue,
-					false => return false
-				}
-			}
-		}
-		true
+		Self::first_invalid(bytes).is_none()
+	}
+	fn first_invalid(bytes: &[u8]) -> Option<usize> {
+		// Validate that all characters are valid query characters
+		Uri::first_invalid_with(bytes, |b| match b {
+			b if Uri::unreserved(b) => true,
+			b if Uri::sub_delims(b) => true,
+			b':' | b'@' | b'/' | b'?' => true,
+			_ => false
+		})
 	}
 }
 impl SubsetOf<Binary> for UriQuery {}
 impl SubsetOf<Utf8> for UriQuery {}
 impl SubsetOf<Ascii> for UriQuery {}
+impl SubsetOf<Uri> for UriQuery {}
+impl PercentEncoded for UriQuery {}
 
 
 /// An ASCII encoded integer (U+0030 '0' ... U+0039 '9')
@@ -202,7 +283,10 @@ impl SubsetOf<Ascii> for UriQuery {}
 pub struct Integer;
 impl Encoding for Integer {
 	fn is_valid(bytes: &[u8]) -> bool {
-		bytes.iter().all(u8::is_ascii_digit)
+		Self::first_invalid(bytes).is_none()
+	}
+	fn first_invalid(bytes: &[u8]) -> Option<usize> {
+		bytes.iter().position(|b| !b.is_ascii_digit())
 	}
 }
 impl SubsetOf<Binary> for Integer {}
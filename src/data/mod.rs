@@ -4,14 +4,15 @@
 //! This mod is used to constrain the HTTP header parts to their context-specific encoding.
 
 pub mod encodings;
+pub mod percent;
 
 use crate::{
 	HttpError,
-	data::encodings::{ Encoding, SubsetOf, Utf8, Integer }
+	data::encodings::{ Encoding, FieldValue, SubsetOf, Utf8, Integer }
 };
 use std::{
-	str, u128, convert::TryFrom, marker::PhantomData, num::ParseIntError, ops::Deref,
-	hash::{ Hash, Hasher }, fmt::{ self, Display, Formatter }
+	str, convert::TryFrom, marker::PhantomData, num::ParseIntError, ops::Deref,
+	hash::{ Hash, Hasher }, fmt::{ self, Display, Formatter, Write }
 };
 
 
@@ -37,7 +38,7 @@ impl<E: Encoding> Data<E> {
 		Self::try_from(superset.bytes)
 	}
 	/// Creates `self` from data with a subset encoding
-	pub fn from_subset<S: Encoding>(subset: Data<S>) -> Self where S: SubsetOf<E> {
+	pub fn from_subset<S: Encoding + SubsetOf<E>>(subset: Data<S>) -> Self {
 		Self::try_from(subset.bytes).unwrap()
 	}
 }
@@ -68,6 +69,22 @@ impl<E: Encoding + SubsetOf<Utf8>> Display for Data<E> {
 		f.write_str(self.as_ref())
 	}
 }
+impl AsRef<str> for Data<Utf8> {
+	fn as_ref(&self) -> &str {
+		str::from_utf8(self).unwrap()
+	}
+}
+impl Display for Data<Utf8> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str(self.as_ref())
+	}
+}
+impl Display for Data<FieldValue> {
+	/// Formats the field value and decodes `obs-text` as ISO-8859-1
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		self.iter().try_for_each(|b| f.write_char(*b as char))
+	}
+}
 impl<E: Encoding> PartialEq for Data<E> {
 	fn eq(&self, other: &Data<E>) -> bool {
 		E::is_eq(self, other)
@@ -131,7 +148,7 @@ impl TryFrom<Data<Integer>> for u128 {
 		Self::from_str_radix(data.as_ref(), 10)
 	}
 }
-impl<'a> TryFrom<Data<Integer>> for u16 {
+impl TryFrom<Data<Integer>> for u16 {
 	type Error = ParseIntError;
 	fn try_from(data: Data<Integer>) -> Result<Self, Self::Error> {
 		Self::from_str_radix(data.as_ref(), 10)
@@ -0,0 +1,92 @@
+//! [Percent-encoding](https://tools.ietf.org/html/rfc3986#section-2.1) for URI components
+
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ Binary, PercentEncoded, Uri, Utf8 }
+	}
+};
+use std::{ marker::PhantomData, convert::TryFrom };
+
+
+/// The URI component specific set of bytes that can be used without percent-encoding
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EncodeSet {
+	/// A single [path segment](https://tools.ietf.org/html/rfc3986#section-3.3) (i.e. `/` is
+	/// encoded)
+	PathSegment,
+	/// A key or value of a [query](https://tools.ietf.org/html/rfc3986#section-3.4) (i.e. the
+	/// separators `&`, `=` and `+` are encoded, too)
+	Query,
+	/// A [fragment](https://tools.ietf.org/html/rfc3986#section-3.5)
+	Fragment,
+	/// The [userinfo](https://tools.ietf.org/html/rfc3986#section-3.2.1)
+	Userinfo
+}
+impl EncodeSet {
+	/// Checks if `b` can be used without percent-encoding
+	fn is_allowed(self, b: u8) -> bool {
+		let is_pchar = Uri::unreserved(b) || Uri::sub_delims(b) || b == b':' || b == b'@';
+		match self {
+			EncodeSet::PathSegment => is_pchar,
+			EncodeSet::Query => (is_pchar || b == b'/' || b == b'?') && !b"&=+".contains(&b),
+			EncodeSet::Fragment => is_pchar || b == b'/' || b == b'?',
+			EncodeSet::Userinfo => Uri::unreserved(b) || Uri::sub_delims(b) || b == b':'
+		}
+	}
+}
+
+
+impl<E: PercentEncoded> Data<E> {
+	/// Percent-encodes `bytes` using the encode set `set`
+	pub fn percent_encode(bytes: impl AsRef<[u8]>, set: EncodeSet) -> Self {
+		const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+		let mut encoded = Vec::with_capacity(bytes.as_ref().len());
+		for b in bytes.as_ref().iter().copied() {
+			match set.is_allowed(b) {
+				true => encoded.push(b),
+				false => {
+					let (high, low) = (HEX[(b >> 4) as usize], HEX[(b & 0x0F) as usize]);
+					encoded.extend_from_slice(&[b'%', high, low])
+				}
+			}
+		}
+		Self::try_from(encoded)
+			.expect("Should never fail because all encode sets are subsets of the URI encodings")
+	}
+
+	/// Decodes all percent-encoded bytes
+	///
+	/// _Note: in contrast to `application/x-www-form-urlencoded`, a `+` is not decoded to a space._
+	pub fn percent_decode(&self) -> Data<Binary> {
+		/// Decodes a single hex digit
+		fn hex(digit: u8) -> u8 {
+			let digit = (digit as char).to_digit(16);
+			digit.expect("Should never fail because the percent-encoding has been validated") as u8
+		}
+
+		let (mut pos, mut decoded) = (0, Vec::with_capacity(self.len()));
+		while pos < self.len() {
+			match &self[pos..] {
+				[b'%', a, b, ..] => {
+					decoded.push(hex(*a) << 4 | hex(*b));
+					pos += 3;
+				},
+				[b, ..] => {
+					decoded.push(*b);
+					pos += 1;
+				},
+				[] => unreachable!("Should never happen because `pos` is within `self`")
+			}
+		}
+		Data{ bytes: decoded, _encoding: PhantomData }
+	}
+	/// Decodes all percent-encoded bytes and validates that the result is UTF-8
+	///
+	/// Fails with `HttpError::InvalidUtf8` if the decoded bytes are not valid UTF-8.
+	pub fn percent_decode_utf8(&self) -> Result<Data<Utf8>, HttpError> {
+		Data::try_from(Vec::from(self.percent_decode())).map_err(|_| HttpError::InvalidUtf8)
+	}
+}
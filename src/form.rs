@@ -0,0 +1,130 @@
+use crate::{
+	QueryString,
+	data::{ Data, encodings::UriQuery }
+};
+use std::{
+	convert::TryFrom,
+	io::{ self, Read }
+};
+
+
+/// Decodes a form-urlencoded byte string (i.e. `+` becomes a space and valid percent-encodings are
+/// decoded)
+pub(crate) fn decode_bytes(bytes: &[u8]) -> Vec<u8> {
+	let (mut pos, mut decoded) = (0, Vec::with_capacity(bytes.len()));
+	while pos < bytes.len() {
+		let hex = |digit: Option<&u8>| digit.and_then(|d| (*d as char).to_digit(16));
+		match (bytes[pos], hex(bytes.get(pos + 1)), hex(bytes.get(pos + 2))) {
+			(b'%', Some(a), Some(b)) => {
+				decoded.push((a << 4 | b) as u8);
+				pos += 3;
+			},
+			(b'+', _, _) => {
+				decoded.push(b' ');
+				pos += 1;
+			},
+			(b, _, _) => {
+				decoded.push(b);
+				pos += 1;
+			}
+		}
+	}
+	decoded
+}
+/// Decodes a form-urlencoded byte string (see `decode_bytes`) and replaces invalid UTF-8 sequences
+/// with U+FFFD
+fn decode(bytes: &[u8]) -> String {
+	String::from_utf8_lossy(&decode_bytes(bytes)).into_owned()
+}
+/// Encodes `string` using the `application/x-www-form-urlencoded` percent-encode set and appends
+/// it to `buf`
+fn encode(string: &str, buf: &mut String) {
+	const HEX: &[u8; 16] = b"0123456789ABCDEF";
+	
+	for b in string.bytes() {
+		match b {
+			b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'*' | b'-' | b'.' | b'_' => {
+				buf.push(b as char)
+			},
+			b' ' => buf.push('+'),
+			_ => {
+				buf.push('%');
+				buf.push(HEX[(b >> 4) as usize] as char);
+				buf.push(HEX[(b & 0x0F) as usize] as char);
+			}
+		}
+	}
+}
+/// Encodes `string` like `encode` into query data
+fn encode_query(string: &str) -> Data<UriQuery> {
+	let mut encoded = String::with_capacity(string.len());
+	encode(string, &mut encoded);
+	Data::try_from(encoded).expect("Should never fail because the encoded string is a valid query")
+}
+
+
+/// `application/x-www-form-urlencoded` support (see the
+/// [WHATWG URL standard](https://url.spec.whatwg.org/#application/x-www-form-urlencoded))
+impl QueryString {
+	/// Parses a form-urlencoded byte string according to the
+	/// [WHATWG URL standard](https://url.spec.whatwg.org/#urlencoded-parsing)
+	///
+	/// The decoded keys and values are stored re-encoded with the form-urlencoded percent-encode
+	/// set, so that `decoded_pairs` yields them unchanged.
+	///
+	/// _Note: like the WHATWG algorithm, this parser never fails; invalid percent-encodings are
+	/// kept as they are and invalid UTF-8 sequences are replaced with U+FFFD._
+	pub fn parse_form(bytes: &[u8]) -> Self {
+		let mut query = Self::new();
+		for pair in bytes.split(|b| *b == b'&').filter(|pair| !pair.is_empty()) {
+			let (key, value) = match pair.iter().position(|b| *b == b'=') {
+				Some(index) => (decode(&pair[..index]), decode(&pair[index + 1..])),
+				None => (decode(pair), String::new())
+			};
+			query.append_decoded(&key, &value);
+		}
+		query
+	}
+	/// Reads a form-urlencoded body from `source` until EOF and parses it (see
+	/// `QueryString::parse_form`)
+	///
+	/// _Note: this reads until EOF; use `Read::take` to limit the body length (e.g. to the
+	/// `Content-Length`)._
+	pub fn read_form(mut source: impl Read) -> Result<Self, io::Error> {
+		let mut body = Vec::new();
+		source.read_to_end(&mut body)?;
+		Ok(Self::parse_form(&body))
+	}
+	
+	/// Form-urlencodes `key` and `value` and appends them to the existing values for `key`
+	pub fn append_decoded(&mut self, key: &str, value: &str) {
+		self.append(encode_query(key), encode_query(value))
+	}
+	/// Gets the first form-urlencoded-decoded value for the decoded `key` if any
+	pub fn get_decoded(&self, key: &str) -> Option<String> {
+		self.iter().find(|(k, _)| decode(k) == key).map(|(_, v)| decode(v))
+	}
+	/// Returns all `(key, value)`-pairs form-urlencoded-decoded in their order (i.e. `+` becomes a
+	/// space and invalid UTF-8 sequences are replaced with U+FFFD)
+	pub fn decoded_pairs(&self) -> Vec<(String, String)> {
+		self.iter().map(|(k, v)| (decode(k), decode(v))).collect()
+	}
+	
+	/// Serializes the pairs according to the
+	/// [WHATWG URL standard](https://url.spec.whatwg.org/#urlencoded-serializing)
+	///
+	/// _Note: unlike the `Display` implementation, this has no leading `?` and always includes the
+	/// `=`._
+	pub fn to_form(&self) -> String {
+		let mut serialized = String::new();
+		for (k, v) in self.decoded_pairs() {
+			if !serialized.is_empty() {
+				serialized.push('&');
+			}
+			encode(&k, &mut serialized);
+			serialized.push('=');
+			encode(&v, &mut serialized);
+		}
+		serialized
+	}
+}
\ No newline at end of file
@@ -0,0 +1,119 @@
+use crate::{ HttpError, QueryError, QueryString, form::decode_bytes };
+use std::num::{ IntErrorKind, ParseIntError };
+
+
+/// A type that can be created from the (percent-decoded) values of a query parameter
+pub trait FromQueryValue: Sized {
+	/// Creates `Self` from a single `value`
+	///
+	/// Fails with the reason if `value` is invalid.
+	fn from_query_value(value: &str) -> Result<Self, &'static str>;
+	/// Creates `Self` from all `values` of a parameter in their order
+	///
+	/// The default implementation requires at least one value and uses the first one (like
+	/// `QueryString::get`); `Option` and `Vec` override this to accept missing or multiple values.
+	fn from_query_values(values: &[String]) -> Result<Self, &'static str> {
+		match values.first() {
+			Some(value) => Self::from_query_value(value),
+			None => Err("the parameter is missing")
+		}
+	}
+}
+/// Implements `FromQueryValue` for integers
+macro_rules! impl_from_query_value_int {
+	($($type:ty),+) => ($(
+		impl FromQueryValue for $type {
+			fn from_query_value(value: &str) -> Result<Self, &'static str> {
+				value.parse().map_err(|e: ParseIntError| match e.kind() {
+					IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+						"the integer is out of range"
+					},
+					_ => "the value is not an integer"
+				})
+			}
+		}
+	)+);
+}
+impl_from_query_value_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+/// Implements `FromQueryValue` for floats
+macro_rules! impl_from_query_value_float {
+	($($type:ty),+) => ($(
+		impl FromQueryValue for $type {
+			fn from_query_value(value: &str) -> Result<Self, &'static str> {
+				value.parse().map_err(|_| "the value is not a number")
+			}
+		}
+	)+);
+}
+impl_from_query_value_float!(f32, f64);
+impl FromQueryValue for bool {
+	/// Accepts `true`, `1` and `on` (as sent for HTML checkboxes) or `false`, `0` and `off`
+	fn from_query_value(value: &str) -> Result<Self, &'static str> {
+		match value {
+			"true" | "1" | "on" => Ok(true),
+			"false" | "0" | "off" => Ok(false),
+			_ => Err("the value is not a boolean")
+		}
+	}
+}
+impl FromQueryValue for String {
+	fn from_query_value(value: &str) -> Result<Self, &'static str> {
+		Ok(value.to_string())
+	}
+}
+impl<T: FromQueryValue> FromQueryValue for Option<T> {
+	fn from_query_value(value: &str) -> Result<Self, &'static str> {
+		T::from_query_value(value).map(Some)
+	}
+	/// Creates `None` if there are no `values`
+	fn from_query_values(values: &[String]) -> Result<Self, &'static str> {
+		match values.is_empty() {
+			true => Ok(None),
+			false => T::from_query_values(values).map(Some)
+		}
+	}
+}
+impl<T: FromQueryValue> FromQueryValue for Vec<T> {
+	fn from_query_value(value: &str) -> Result<Self, &'static str> {
+		Ok(vec![T::from_query_value(value)?])
+	}
+	/// Converts every value and creates an empty vector if there are no `values`
+	fn from_query_values(values: &[String]) -> Result<Self, &'static str> {
+		values.iter().map(|value| T::from_query_value(value)).collect()
+	}
+}
+
+
+/// A type that can be extracted from a whole query string (e.g. a struct that implements this
+/// by calling `QueryString::get_as` for each field)
+pub trait FromQuery: Sized {
+	/// Extracts `Self` from `query`
+	fn from_query(query: &QueryString) -> Result<Self, QueryError>;
+}
+
+
+impl QueryString {
+	/// Gets the form-urlencoded-decoded values for the parameter `name` and converts them to `T`
+	///
+	/// _Note: the keys and values are decoded like `get_decoded` does it (i.e. a `+` becomes a
+	/// space); use `%2B` for a literal `+`._
+	///
+	/// Fails with a `QueryError` that names the parameter if a value is not valid UTF-8
+	/// (`HttpError::InvalidUtf8`) or cannot be converted (`HttpError::InvalidParameter`).
+	pub fn get_as<T: FromQueryValue>(&self, name: &str) -> Result<T, QueryError> {
+		let error = |kind, reason| QueryError{ kind, name: name.to_string(), reason };
+		
+		// Collect the decoded values
+		let mut values = Vec::new();
+		for (_, v) in self.iter().filter(|(k, _)| decode_bytes(k) == name.as_bytes()) {
+			let value = String::from_utf8(decode_bytes(v)).map_err(|_| HttpError::InvalidUtf8)
+				.map_err(|kind| error(kind, "the decoded value is not valid UTF-8"))?;
+			values.push(value);
+		}
+		T::from_query_values(&values).map_err(|reason| error(HttpError::InvalidParameter, reason))
+	}
+	/// Extracts `T` from the query string (see `FromQuery`)
+	pub fn extract<T: FromQuery>(&self) -> Result<T, QueryError> {
+		T::from_query(self)
+	}
+}
@@ -1,28 +1,30 @@
 use crate::{
-	HttpError, Header, RequestHeader, ResponseHeader,
+	HttpError, Header, HeaderFields, Method, RequestHeader, RequestTarget, ResponseHeader,
+	StatusCode, Version,
+	typed::TypedHeader,
 	data::{
 		Data,
-		encodings::{ Ascii, HeaderFieldKey, Uri }
+		encodings::{ Ascii, FieldValue, HeaderFieldKey, Uri }
 	}
 };
-use std::{ collections::HashMap, convert::TryFrom };
+use std::convert::TryFrom;
 
 
 /// A HTTP-response header builder
 pub struct RequestBuilder {
-	method: Option<Data<Ascii>>,
+	method: Option<Method>,
 	uri: Option<Data<Uri>>,
-	version: Option<Data<Ascii>>,
-	header_fields: HashMap<Data<HeaderFieldKey>, Data<Ascii>>
+	version: Option<Version>,
+	header_fields: HeaderFields
 }
 impl RequestBuilder {
 	/// Creates a new request builder
 	pub fn new() -> Self {
-		Self{ method: None, uri: None, version: None, header_fields: HashMap::new() }
+		Self{ method: None, uri: None, version: None, header_fields: HeaderFields::new() }
 	}
 	
 	/// Sets the request method
-	pub fn method(mut self, method: Data<Ascii>) -> Self {
+	pub fn method(mut self, method: Method) -> Self {
 		self.method = Some(method);
 		self
 	}
@@ -32,16 +34,28 @@ impl RequestBuilder {
 		self
 	}
 	/// Sets the HTTP version
-	pub fn version(mut self, version: Data<Ascii>) -> Self {
+	pub fn version(mut self, version: Version) -> Self {
 		self.version = Some(version);
 		self
 	}
 	
-	/// Inserts a header field with `key`-`value`
-	pub fn field(mut self, key: Data<HeaderFieldKey>, value: Data<Ascii>) -> Self {
+	/// Inserts a header field with `key`-`value` and replaces all existing fields for `key`
+	pub fn field(mut self, key: Data<HeaderFieldKey>, value: Data<FieldValue>) -> Self {
 		self.header_fields.insert(key, value);
 		self
 	}
+	/// Appends a header field with `key`-`value` without replacing existing fields for `key`
+	pub fn append_field(mut self, key: Data<HeaderFieldKey>, value: Data<FieldValue>) -> Self {
+		self.header_fields.append(key, value);
+		self
+	}
+	/// Inserts the encoded typed `header` and replaces all existing fields for `H::NAME`
+	pub fn typed_insert<H: TypedHeader>(mut self, header: H) -> Self {
+		let key = Data::try_from(H::NAME)
+			.expect("Should never fail because typed header names are valid field names");
+		self.header_fields.insert(key, header.encode());
+		self
+	}
 	
 	/// Builds the request header
 	pub fn build(self) -> Result<RequestHeader, HttpError> {
@@ -50,68 +64,115 @@ impl RequestBuilder {
 		let uri = self.uri.ok_or(HttpError::ApiMisuse)?;
 		let version = self.version.ok_or(HttpError::ApiMisuse)?;
 		
-		// Convert the URI into a generic ASCII field
+		// Validate the request target against the method
+		let target = RequestTarget::parse(&method, &uri)?;
+		
+		// Convert the method, the URI and the version into generic status line fields
+		let method_ascii: Data<Ascii> = Data::try_from(method.as_bytes())
+			.expect("Should never fail because all methods are a subset of ASCII");
 		let uri_ascii: Data<Ascii> = Data::try_from(&uri as &[u8])?;
+		let version_value: Data<FieldValue> = Data::try_from(version.as_bytes())
+			.expect("Should never fail because all versions are valid field values");
 		Ok(RequestHeader {
 			header: Header {
-				status_line: (method, uri_ascii, version),
+				status_line: (method_ascii, uri_ascii, version_value),
 				fields: self.header_fields
-			}, uri
+			}, method, uri, target, version
 		})
 	}
 }
+impl Default for RequestBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
 
 
 /// A HTTP-response header builder
 pub struct ResponseBuilder {
-	version: Option<Data<Ascii>>,
+	version: Option<Version>,
 	status: Option<u16>,
-	reason: Option<Data<Ascii>>,
-	header_fields: HashMap<Data<HeaderFieldKey>, Data<Ascii>>
+	reason: Option<Data<FieldValue>>,
+	default_reason: Option<&'static str>,
+	header_fields: HeaderFields
 }
 impl ResponseBuilder {
 	/// Creates a new response builder
 	pub fn new() -> Self {
-		Self{ version: None, status: None, reason: None, header_fields: HashMap::new() }
+		Self {
+			version: None, status: None, reason: None, default_reason: None,
+			header_fields: HeaderFields::new()
+		}
 	}
 	
-	/// Sets the HTTP version to the literal `version`
-	pub fn version(mut self, version: Data<Ascii>) -> Self {
+	/// Sets the HTTP version
+	pub fn version(mut self, version: Version) -> Self {
 		self.version = Some(version);
 		self
 	}
 	/// Sets the status code
 	pub fn status(mut self, status: u16) -> Self {
 		self.status = Some(status);
+		self.default_reason = None;
+		self
+	}
+	/// Sets the status code and uses its canonical reason phrase if no reason is set explicitly
+	pub fn status_code(mut self, status: StatusCode) -> Self {
+		self.status = Some(status.as_u16());
+		self.default_reason = status.canonical_reason();
 		self
 	}
 	/// Sets the status reason
-	pub fn reason(mut self, info: Data<Ascii>) -> Self {
+	pub fn reason(mut self, info: Data<FieldValue>) -> Self {
 		self.reason = Some(info);
 		self
 	}
 	
-	/// Inserts a header field with `key`-`value`
-	pub fn field(mut self, key: Data<HeaderFieldKey>, value: Data<Ascii>) -> Self {
+	/// Inserts a header field with `key`-`value` and replaces all existing fields for `key`
+	pub fn field(mut self, key: Data<HeaderFieldKey>, value: Data<FieldValue>) -> Self {
 		self.header_fields.insert(key, value);
 		self
 	}
+	/// Appends a header field with `key`-`value` without replacing existing fields for `key`
+	pub fn append_field(mut self, key: Data<HeaderFieldKey>, value: Data<FieldValue>) -> Self {
+		self.header_fields.append(key, value);
+		self
+	}
+	/// Inserts the encoded typed `header` and replaces all existing fields for `H::NAME`
+	pub fn typed_insert<H: TypedHeader>(mut self, header: H) -> Self {
+		let key = Data::try_from(H::NAME)
+			.expect("Should never fail because typed header names are valid field names");
+		self.header_fields.insert(key, header.encode());
+		self
+	}
 	
 	/// Builds the response header
 	pub fn build(self) -> Result<ResponseHeader, HttpError> {
 		// Unwrap status fields
 		let version = self.version.ok_or(HttpError::ApiMisuse)?;
-		let status = self.status.ok_or(HttpError::ApiMisuse)?;
-		let reason = self.reason.ok_or(HttpError::ApiMisuse)?;
+		let status = StatusCode::try_from(self.status.ok_or(HttpError::ApiMisuse)?)?;
+		let reason = match (self.reason, self.default_reason) {
+			(Some(reason), _) => reason,
+			(None, Some(default_reason)) => Data::try_from(default_reason)
+				.expect("Should never fail because all canonical reason phrases are valid field values"),
+			(None, None) => Err(HttpError::ApiMisuse)?
+		};
 		
-		// Convert the status integer into a generic ASCII field
+		// Convert the version and the status integer into generic ASCII fields
+		let version_ascii: Data<Ascii> = Data::try_from(version.as_bytes())
+			.expect("Should never fail because all versions are a subset of ASCII");
 		let status_ascii: Data<Ascii> = Data::try_from(status.to_string().into_bytes())
 			.expect("Should never fail because all number literals are a subset of ASCII");
 		Ok(ResponseHeader {
 			header: Header {
-				status_line: (version, status_ascii, reason),
+				status_line: (version_ascii, status_ascii, reason),
 				fields: self.header_fields
-			}, status
+			}, version, status
 		})
 	}
+}
+impl Default for ResponseBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
 }
\ No newline at end of file
@@ -0,0 +1,76 @@
+use crate::helpers::slice_ext::SliceExt;
+
+
+/// Size limits that are enforced during parsing
+///
+/// Each exceeded limit results in a specific error so that a server can reply with an appropriate
+/// status code (e.g. `431 Request Header Fields Too Large` or `414 URI Too Long`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseLimits {
+	/// The maximum amount of header fields (exceeding results in `HttpError::TooManyFields`)
+	pub max_fields: usize,
+	/// The maximum length of a single header field line excluding the line break (exceeding results
+	/// in `HttpError::FieldLineTooLong`); an obs-folded field is measured as the sum of its physical
+	/// lines without the interior line breaks
+	pub max_field_line_len: usize,
+	/// The maximum length of the request target (exceeding results in `HttpError::UriTooLong`)
+	pub max_uri_len: usize,
+	/// The maximum total size of the header including the trailing empty line (exceeding results
+	/// in `HttpError::HeaderTooLarge`)
+	pub max_header_len: usize
+}
+impl ParseLimits {
+	/// Creates a limit set that does not limit anything
+	pub fn unlimited() -> Self {
+		Self {
+			max_fields: usize::MAX, max_field_line_len: usize::MAX,
+			max_uri_len: usize::MAX, max_header_len: usize::MAX
+		}
+	}
+}
+impl Default for ParseLimits {
+	/// Creates a limit set with sane defaults for servers
+	fn default() -> Self {
+		Self{ max_fields: 100, max_field_line_len: 8192, max_uri_len: 8192, max_header_len: 65536 }
+	}
+}
+
+
+/// A parser configuration
+///
+/// The default configuration is strict; the leniency options implement the
+/// [recipient tolerances](https://tools.ietf.org/html/rfc7230#section-3.2.4) for legacy clients.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct ParserConfig {
+	/// The size limits to enforce
+	pub limits: ParseLimits,
+	/// Whether to accept a bare `LF` as line ending in addition to `CRLF`
+	pub allow_bare_lf: bool,
+	/// Whether to unfold obsolete line folding (`obs-fold`) into a single space instead of
+	/// rejecting it
+	pub unfold_obs_fold: bool
+}
+impl ParserConfig {
+	/// Creates a strict configuration that does not limit anything
+	pub fn unlimited() -> Self {
+		Self{ limits: ParseLimits::unlimited(), allow_bare_lf: false, unfold_obs_fold: false }
+	}
+	/// Creates a lenient configuration with the default limits that accepts bare `LF`s and unfolds
+	/// obsolete line folding
+	pub fn lenient() -> Self {
+		Self{ limits: ParseLimits::default(), allow_bare_lf: true, unfold_obs_fold: true }
+	}
+	
+	/// The byte sequences that terminate a header
+	pub(crate) fn header_ends(&self) -> &'static[&'static[u8]] {
+		match self.allow_bare_lf {
+			true => &[b"\r\n\r\n", b"\n\r\n", b"\n\n"],
+			false => &[b"\r\n\r\n"]
+		}
+	}
+	/// Gets the length of the header in `data` including the terminating empty line if `data`
+	/// contains a complete header
+	pub(crate) fn header_len(&self, data: &[u8]) -> Option<usize> {
+		data.find_end_any(self.header_ends())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ FieldValue, HeaderFieldKey }
+	},
+	helpers::ordered_pairs::{ self, OrderedPairs }
+};
+use std::{ convert::TryFrom, iter::FromIterator };
+
+
+/// An iterator over all values for a specific key
+pub type GetAll<'a, 'k> =
+	ordered_pairs::GetAll<'a, 'k, Data<HeaderFieldKey>, Data<FieldValue>, Data<HeaderFieldKey>>;
+/// An iterator over all field lines of a `HeaderFields` instance
+pub type Iter<'a> = ordered_pairs::Iter<'a, Data<HeaderFieldKey>, Data<FieldValue>>;
+
+
+/// An insertion-ordered, multi-valued header field store
+///
+/// A field key may occur multiple times (e.g. `Set-Cookie`); each occurrence is stored as separate
+/// field line so that nothing gets lost. The field lines keep the order in which they were added.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct HeaderFields {
+	fields: OrderedPairs<Data<HeaderFieldKey>, Data<FieldValue>>
+}
+impl HeaderFields {
+	/// Creates a new, empty field store
+	pub fn new() -> Self {
+		Self{ fields: OrderedPairs::new() }
+	}
+	
+	/// Gets the first value for `key` if any
+	pub fn get(&self, key: &Data<HeaderFieldKey>) -> Option<&Data<FieldValue>> {
+		self.fields.get(key)
+	}
+	/// Returns an iterator over all values for `key` in the order they were added
+	pub fn get_all<'a, 'k>(&'a self, key: &'k Data<HeaderFieldKey>) -> GetAll<'a, 'k> {
+		self.fields.get_all(key)
+	}
+	/// Gets all values for `key` combined into a single comma-separated value according to
+	/// [RFC 7230](https://tools.ietf.org/html/rfc7230#section-3.2.2)
+	///
+	/// _Note: this fails with `HttpError::ApiMisuse` for multiple `Set-Cookie`-values because they
+	/// cannot be combined without changing their semantics._
+	pub fn get_combined(&self, key: &Data<HeaderFieldKey>)
+		-> Result<Option<Data<FieldValue>>, HttpError>
+	{
+		const SET_COOKIE: &str = "Set-Cookie";
+		const SEPARATOR: &[u8] = b", ";
+		
+		// Get the values and check if we can combine them
+		let values: Vec<&Data<FieldValue>> = self.get_all(key).collect();
+		match values.len() {
+			0 => return Ok(None),
+			1 => return Ok(Some(values[0].clone())),
+			_ if SET_COOKIE == key => Err(HttpError::ApiMisuse)?,
+			_ => ()
+		}
+		
+		// Combine the values
+		let mut combined = Vec::new();
+		for value in values {
+			if !combined.is_empty() {
+				combined.extend_from_slice(SEPARATOR);
+			}
+			combined.extend_from_slice(value);
+		}
+		Ok(Some(Data::try_from(combined)?))
+	}
+	/// Checks if there is at least one value for `key`
+	pub fn contains_key(&self, key: &Data<HeaderFieldKey>) -> bool {
+		self.fields.contains_key(key)
+	}
+	
+	/// Inserts `value` for `key` and replaces all existing values for `key`
+	///
+	/// The new field takes the position of the first replaced field or is appended if there is no
+	/// field for `key` yet.
+	pub fn insert(&mut self, key: Data<HeaderFieldKey>, value: Data<FieldValue>) {
+		self.fields.insert(key, value)
+	}
+	/// Appends `value` to the existing values for `key`
+	pub fn append(&mut self, key: Data<HeaderFieldKey>, value: Data<FieldValue>) {
+		self.fields.append(key, value)
+	}
+	/// Removes all values for `key` and returns them
+	pub fn remove(&mut self, key: &Data<HeaderFieldKey>) -> Vec<Data<FieldValue>> {
+		self.fields.remove(key)
+	}
+	
+	/// The amount of field lines (i.e. each value of a multi-valued field counts separately)
+	pub fn len(&self) -> usize {
+		self.fields.len()
+	}
+	/// Checks if there are no fields
+	pub fn is_empty(&self) -> bool {
+		self.fields.is_empty()
+	}
+	
+	/// Returns an iterator over all field lines as `(key, value)`-pairs
+	pub fn iter(&self) -> Iter<'_> {
+		self.fields.iter()
+	}
+}
+impl FromIterator<(Data<HeaderFieldKey>, Data<FieldValue>)> for HeaderFields {
+	/// Collects the `(key, value)`-pairs by appending them
+	fn from_iter<T>(iter: T) -> Self
+		where T: IntoIterator<Item = (Data<HeaderFieldKey>, Data<FieldValue>)>
+	{
+		Self{ fields: iter.into_iter().collect() }
+	}
+}
+impl<'a> IntoIterator for &'a HeaderFields {
+	type Item = (&'a Data<HeaderFieldKey>, &'a Data<FieldValue>);
+	type IntoIter = Iter<'a>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+use crate::{
+	HttpError, RequestHeader,
+	data::{
+		Data,
+		encodings::{ FieldValue, HeaderFieldKey }
+	},
+	helpers::slice_ext::{ ByteSliceExt, SliceExt }
+};
+use std::{ str, convert::TryInto };
+
+
+impl RequestHeader {
+	/// Performs an additional, hardened validation of the message framing to defend against
+	/// [request smuggling](https://tools.ietf.org/html/rfc7230#section-9.5)
+	///
+	/// This rejects requests
+	///  - that contain both a `Content-Length` and a `Transfer-Encoding`
+	///    (`HttpError::AmbiguousFraming`)
+	///  - with a malformed `Content-Length` (`HttpError::InvalidContentLength`)
+	///  - with multiple differing `Content-Length` values (`HttpError::ConflictingContentLength`)
+	///  - with a `Transfer-Encoding` where `chunked` is not the final coding or is applied more
+	///    than once (`HttpError::ChunkedNotFinal`)
+	///
+	/// _Note: whitespace between a field name and the colon is always rejected during parsing
+	/// (`HttpError::WhitespaceBeforeColon`)._
+	pub fn validate_hardened(&self) -> Result<(), HttpError> {
+		let content_length: Data<HeaderFieldKey> = "Content-Length".try_into().unwrap();
+		let transfer_encoding: Data<HeaderFieldKey> = "Transfer-Encoding".try_into().unwrap();
+		
+		// Validate the framing headers
+		let fields = &self.header.fields;
+		let has_content_length = fields.contains_key(&content_length);
+		let has_transfer_encoding = fields.contains_key(&transfer_encoding);
+		if has_content_length && has_transfer_encoding {
+			Err(HttpError::AmbiguousFraming)?
+		}
+		if has_content_length {
+			content_length_value(fields.get_all(&content_length))?;
+		}
+		if has_transfer_encoding {
+			validate_transfer_encoding(fields.get_all(&transfer_encoding))?;
+		}
+		Ok(())
+	}
+}
+
+
+/// Validates that all `Content-Length` `values` are valid and identical and returns the length
+pub(crate) fn content_length_value<'a>(values: impl Iterator<Item = &'a Data<FieldValue>>)
+	-> Result<u64, HttpError>
+{
+	let mut content_length = None;
+	for value in values {
+		// A value may be a list of identical values (see RFC 7230, section 3.3.2)
+		for element in value.split_pat(b",") {
+			let element = element.trim();
+			if element.is_empty() || !element.iter().all(u8::is_ascii_digit) {
+				Err(HttpError::InvalidContentLength)?
+			}
+			let element: u64 = str::from_utf8(element).unwrap().parse()
+				.map_err(|_| HttpError::InvalidContentLength)?;
+			
+			// Compare the value with the previous values
+			match content_length.replace(element) {
+				Some(previous) if previous != element => Err(HttpError::ConflictingContentLength)?,
+				_ => ()
+			}
+		}
+	}
+	content_length.ok_or(HttpError::InvalidContentLength)
+}
+
+
+/// Validates that `chunked` is the final coding of all `Transfer-Encoding` `values` and is applied
+/// only once
+fn validate_transfer_encoding<'a>(values: impl Iterator<Item = &'a Data<FieldValue>>)
+	-> Result<(), HttpError>
+{
+	// Collect the coding names and ignore empty list elements
+	let mut codings = Vec::new();
+	for value in values {
+		for element in value.split_pat(b",") {
+			let name = element.splitn_pat(2, b";").next().unwrap().trim();
+			if !name.is_empty() {
+				codings.push(name);
+			}
+		}
+	}
+	
+	// Validate that `chunked` occurs exactly once as final coding
+	let chunked: Vec<usize> = codings.iter().enumerate()
+		.filter(|(_, name)| name.eq_ignore_ascii_case(b"chunked"))
+		.map(|(index, _)| index).collect();
+	match chunked.as_slice() {
+		[index] if index + 1 == codings.len() => Ok(()),
+		_ => Err(HttpError::ChunkedNotFinal)
+	}
+}
\ No newline at end of file
@@ -1,19 +1,18 @@
 use crate::{
-	HttpError,
+	HttpError, ParseError, HeaderFields, HeaderRef, Method, ParserConfig, RequestTarget, StatusCode,
+	Version,
+	typed::TypedHeader,
 	data::{
 		Data,
-		encodings::{ Ascii, HeaderFieldKey, Uri, Integer }
+		encodings::{ Ascii, FieldValue, HeaderFieldKey, Uri }
 	},
 	helpers::{
-		iter_ext::IterExt,
-		io_ext::{ ReadExt, WriteExt },
-		slice_ext::{ ByteSliceExt, SliceExt }
+		io_ext::{ ReadExt, WriteExt, DataOverread }
 	}
 };
 use std::{
-	collections::HashMap,
 	io::{ self, Read, Cursor },
-	convert::{ TryFrom, TryInto }
+	convert::TryFrom
 };
 
 
@@ -22,62 +21,72 @@ use std::{
 /// Can be converted into a `RequestHeader`/`ResponseHeader` using the `TryFrom`/`TryInto`-traits
 #[derive(Debug, Clone)]
 pub struct Header {
-	/// The header status line
-	pub status_line: (Data<Ascii>, Data<Ascii>, Data<Ascii>),
+	/// The header status line (the last part is a field value so that a reason phrase cannot
+	/// contain line breaks)
+	pub status_line: (Data<Ascii>, Data<Ascii>, Data<FieldValue>),
 	/// The header fields
-	pub fields: HashMap<Data<HeaderFieldKey>, Data<Ascii>>
+	pub fields: HeaderFields
 }
 impl Header {
 	/// Checks if `data` starts with a header-like structure and returns either
 	/// `Some((header, body))` or `None` if no header-like structure was found
 	pub fn scan(data: &[u8]) -> Option<(&[u8], &[u8])> {
-		const END: &[u8] = b"\r\n\r\n";
-		let index = data.find(&END)?;
-		Some(data.split_at(index + END.len()))
+		Self::scan_with(data, &ParserConfig::default())
+	}
+	/// Checks if `data` starts with a header-like structure using the line endings allowed by
+	/// `config` and returns either `Some((header, body))` or `None` if no header-like structure was
+	/// found
+	pub fn scan_with<'a>(data: &'a[u8], config: &ParserConfig) -> Option<(&'a[u8], &'a[u8])> {
+		Some(data.split_at(config.header_len(data)?))
 	}
 	/// Reads from `source` into `buf` until a HTTP-header-end is matched or `buf` is filled
 	/// completely
 	///
 	/// Returns either `Some(header_len)` if the header end has been matched or `None` if `buf` has
 	/// been filled completely without a match.
-	pub fn read(mut source: impl Read, buf: &mut[u8]) -> Result<Option<usize>, io::Error> {
-		const END: &[u8] = b"\r\n\r\n";
-		source.read_until(buf, &END)
+	pub fn read(source: impl Read, buf: &mut[u8]) -> Result<Option<usize>, io::Error> {
+		Self::read_with(source, buf, &ParserConfig::default())
+	}
+	/// Reads from `source` into `buf` until a HTTP-header-end as allowed by `config` is matched or
+	/// `buf` is filled completely
+	///
+	/// Returns either `Some(header_len)` if the header end has been matched or `None` if `buf` has
+	/// been filled completely without a match.
+	pub fn read_with(mut source: impl Read, buf: &mut[u8], config: &ParserConfig)
+		-> Result<Option<usize>, io::Error>
+	{
+		source.read_until(buf, config.header_ends())
+	}
+	/// Reads chunk-wise from `source` until a HTTP-header-end is matched or `limit` bytes have been
+	/// read
+	///
+	/// In contrast to `read`, this function does not read byte-per-byte but in larger chunks and is
+	/// thus suitable for unbuffered sources like a `TcpStream`. Returns either
+	/// `Some((header, body))` if the header end has been matched, where `body` contains all bytes
+	/// that have been read after the header end, or `None` if `limit` has been reached or the
+	/// source is exhausted without a match.
+	pub fn read_chunked(source: impl Read, limit: usize)
+		-> Result<Option<DataOverread>, io::Error>
+	{
+		Self::read_chunked_with(source, limit, &ParserConfig::default())
+	}
+	/// Reads chunk-wise from `source` until a HTTP-header-end as allowed by `config` is matched or
+	/// `limit` bytes have been read (see `read_chunked`)
+	pub fn read_chunked_with(mut source: impl Read, limit: usize, config: &ParserConfig)
+		-> Result<Option<DataOverread>, io::Error>
+	{
+		source.read_until_chunked(limit, config.header_ends())
 	}
 	
-	/// Parses a HTTP request header from `bytes`
+	/// Parses a HTTP header from `bytes` without enforcing any limits
 	pub fn parse(bytes: &[u8]) -> Result<Self, HttpError> {
-		const SPACE: &[u8] = b" ";
-		const SEPARATOR: &[u8] = b":";
-		const NEWLINE: &[u8] = b"\r\n";
-		const END: &[u8] = b"\r\n\r\n";
-		
-		// Split data into header and body
-		let header_body = bytes.splitn_pat(2, &END)
-			.collect_min(2).ok_or(HttpError::TruncatedData)?;
-		let mut header = header_body[0].split_pat(&NEWLINE);
-		
-		// Parse status line
-		let status_line = header.next().ok_or(HttpError::ProtocolViolation)?
-			.trim().splitn_pat(3, &SPACE)
-			.collect_exact(3).ok_or(HttpError::ProtocolViolation)?;
-		let status_line = (
-			status_line[0].try_into()?,
-			status_line[1].try_into()?,
-			status_line[2].try_into()?
-		);
-		
-		// Parse header fields
-		let mut fields = HashMap::new();
-		while let Some(line) = header.next() {
-			let key_value = line.splitn_pat(2, &SEPARATOR)
-				.collect_min(2).ok_or(HttpError::ProtocolViolation)?;
-			fields.insert(
-				Data::try_from(key_value[0])?,
-				Data::try_from(key_value[1].trim())?
-			);
-		}
-		Ok(Self{ status_line, fields })
+		Ok(HeaderRef::parse(bytes)?.to_owned())
+	}
+	/// Parses a HTTP header from `bytes` using `config`
+	///
+	/// Returns a detailed error that contains the position and the cause of the error.
+	pub fn parse_with(bytes: &[u8], config: &ParserConfig) -> Result<Self, ParseError> {
+		Ok(HeaderRef::parse_with(bytes, config)?.to_owned())
 	}
 	
 	/// Serializes and writes the header to `sink` and returns the amount of bytes written
@@ -119,14 +128,23 @@ impl Header {
 macro_rules! repetitive_header_fns {
 	($struct:ty) => {
 		impl $struct {
-			/// Gets the field for `key` if any
-			pub fn field(&self, key: &Data<HeaderFieldKey>) -> Option<&Data<Ascii>> {
-				self.header.fields.get(&key)
+			/// Gets the first field for `key` if any
+			pub fn field(&self, key: &Data<HeaderFieldKey>) -> Option<&Data<FieldValue>> {
+				self.header.fields.get(key)
 			}
-			/// Returns an iterator over all header fields
-			pub fn fields(&self) -> &HashMap<Data<HeaderFieldKey>, Data<Ascii>> {
+			/// The header fields
+			pub fn fields(&self) -> &HeaderFields {
 				&self.header.fields
 			}
+			/// Gets and decodes the typed header `H` from all fields for `H::NAME` if any
+			pub fn typed_get<H: TypedHeader>(&self) -> Result<Option<H>, HttpError> {
+				let key: Data<HeaderFieldKey> = Data::try_from(H::NAME)?;
+				let values: Vec<&Data<FieldValue>> = self.header.fields.get_all(&key).collect();
+				match values.is_empty() {
+					true => Ok(None),
+					false => Ok(Some(H::decode(&values)?))
+				}
+			}
 			
 			/// Serializes and writes the header to `sink` and returns the amount of bytes written
 			pub fn write(&self, sink: impl WriteExt) -> Result<usize, io::Error> {
@@ -155,20 +173,27 @@ macro_rules! repetitive_header_fns {
 #[derive(Debug, Clone)]
 pub struct RequestHeader{
 	pub(in crate::header) header: Header,
-	pub(in crate::header) uri: Data<Uri>
+	pub(in crate::header) method: Method,
+	pub(in crate::header) uri: Data<Uri>,
+	pub(in crate::header) target: RequestTarget,
+	pub(in crate::header) version: Version
 }
 impl RequestHeader {
 	/// The request method
-	pub fn method(&self) -> &Data<Ascii> {
-		&self.header.status_line.0
+	pub fn method(&self) -> &Method {
+		&self.method
 	}
 	/// The requested URI
 	pub fn uri(&self) -> &Data<Uri> {
 		&self.uri
 	}
+	/// The request target in its specific form
+	pub fn target(&self) -> &RequestTarget {
+		&self.target
+	}
 	/// The HTTP version
-	pub fn version(&self) -> &Data<Ascii> {
-		&self.header.status_line.2
+	pub fn version(&self) -> &Version {
+		&self.version
 	}
 }
 repetitive_header_fns!(RequestHeader);
@@ -176,8 +201,11 @@ impl TryFrom<Header> for RequestHeader {
 	type Error = HttpError;
 	/// Tries to create a `RequestHeader` from a `Header`
 	fn try_from(header: Header) -> Result<Self, Self::Error> {
+		let method = Method::try_from(&header.status_line.0 as &[u8])?;
 		let uri = Data::try_from(&header.status_line.1 as &[u8])?;
-		Ok(Self{ header, uri })
+		let target = RequestTarget::parse(&method, &uri)?;
+		let version = Version::try_from(&header.status_line.2 as &[u8])?;
+		Ok(Self{ header, method, uri, target, version })
 	}
 }
 
@@ -186,29 +214,30 @@ impl TryFrom<Header> for RequestHeader {
 #[derive(Debug, Clone)]
 pub struct ResponseHeader {
 	pub(in crate::header) header: Header,
-	pub(in crate::header) status: u16
+	pub(in crate::header) version: Version,
+	pub(in crate::header) status: StatusCode
 }
 impl ResponseHeader {
 	/// The HTTP version
-	pub fn version(&self) -> &Data<Ascii> {
-		&self.header.status_line.0
+	pub fn version(&self) -> &Version {
+		&self.version
 	}
 	/// The status code
-	pub fn status(&self) -> u16 {
+	pub fn status(&self) -> StatusCode {
 		self.status
 	}
 	/// The status reason
-	pub fn reason(&self) -> &Data<Ascii> {
+	pub fn reason(&self) -> &Data<FieldValue> {
 		&self.header.status_line.2
 	}
 }
 repetitive_header_fns!(ResponseHeader);
 impl TryFrom<Header> for ResponseHeader {
 	type Error = HttpError;
-	/// Tries to create a `RequestHeader` from a `Header`
+	/// Tries to create a `ResponseHeader` from a `Header`
 	fn try_from(header: Header) -> Result<Self, Self::Error> {
-		let status_data: Data<Integer> = Data::try_from(&header.status_line.1 as &[u8])?;
-		let status = status_data.try_into().map_err(|_| HttpError::ProtocolViolation)?;
-		Ok(Self{ header, status })
+		let version = Version::try_from(&header.status_line.0 as &[u8])?;
+		let status = StatusCode::try_from(&header.status_line.1 as &[u8])?;
+		Ok(Self{ header, version, status })
 	}
 }
\ No newline at end of file
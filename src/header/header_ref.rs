@@ -0,0 +1,396 @@
+use crate::{
+	HttpError, Component, ParseError, Header, HeaderFields, ParserConfig, RequestHeader,
+	ResponseHeader, Method, RequestTarget, StatusCode, Version,
+	data::{
+		Data,
+		encodings::{ Encoding, Ascii, FieldValue, HeaderFieldKey }
+	},
+	helpers::{
+		iter_ext::IterExt,
+		slice_ext::{ ByteSliceExt, SliceExt }
+	}
+};
+use std::{ str, borrow::Cow, convert::{ TryFrom, TryInto } };
+
+
+/// Validates `bytes` against the encoding `E` and returns them unchanged
+fn validate<E: Encoding>(bytes: &[u8]) -> Result<&[u8], HttpError> {
+	match E::is_valid(bytes) {
+		true => Ok(bytes),
+		false => Err(HttpError::InvalidEncoding)
+	}
+}
+
+
+/// A helper to create `ParseError`s with position information for some parsed data
+struct ErrorContext<'a> {
+	bytes: &'a[u8]
+}
+impl<'a> ErrorContext<'a> {
+	/// Creates an error at `offset`
+	fn at(&self, offset: usize, kind: HttpError, component: Component, reason: &'static str)
+		-> ParseError
+	{
+		let line = self.bytes[.. offset.min(self.bytes.len())].iter()
+			.filter(|b| **b == b'\n').count() + 1;
+		ParseError{ kind, offset, line, component, reason }
+	}
+	/// Creates an error at the beginning of `part`
+	///
+	/// _Note: `part` must be a sub-slice of the parsed data_
+	fn of(&self, part: &[u8], kind: HttpError, component: Component, reason: &'static str)
+		-> ParseError
+	{
+		self.at(self.offset(part), kind, component, reason)
+	}
+	/// Validates `part` against the encoding `E` and returns it unchanged
+	fn validate<E: Encoding>(&self, part: &'a[u8], component: Component, reason: &'static str)
+		-> Result<&'a[u8], ParseError>
+	{
+		match E::first_invalid(part) {
+			None => Ok(part),
+			Some(index) => {
+				let offset = self.offset(part) + index;
+				Err(self.at(offset, HttpError::InvalidEncoding, component, reason))
+			}
+		}
+	}
+	
+	/// Computes the offset of `part` within the parsed data
+	fn offset(&self, part: &[u8]) -> usize {
+		part.as_ptr() as usize - self.bytes.as_ptr() as usize
+	}
+}
+
+
+/// The three borrowed parts of a status line
+type StatusLine<'a> = (&'a[u8], &'a[u8], &'a[u8]);
+
+
+/// A borrowed, zero-copy HTTP/1.* header implementation
+///
+/// All parts are slices into the parsed bytes; they are validated against the same encodings as
+/// their `Header` counterparts. Can be converted into a `RequestHeaderRef`/`ResponseHeaderRef`
+/// using the `TryFrom`/`TryInto`-traits.
+#[derive(Debug, Clone)]
+pub struct HeaderRef<'a> {
+	status_line: StatusLine<'a>,
+	fields: Vec<(&'a[u8], Cow<'a, [u8]>)>
+}
+impl<'a> HeaderRef<'a> {
+	/// Parses a HTTP header from `bytes` without copying any data and without enforcing any limits
+	pub fn parse(bytes: &'a[u8]) -> Result<Self, HttpError> {
+		Ok(Self::parse_with(bytes, &ParserConfig::unlimited())?)
+	}
+	/// Parses a HTTP header from `bytes` without copying any data using `config`
+	///
+	/// Returns a detailed error that contains the position and the cause of the error.
+	///
+	/// _Note: if obsolete line folding is unfolded, the unfolded field values are copied._
+	pub fn parse_with(bytes: &'a[u8], config: &ParserConfig) -> Result<Self, ParseError> {
+		const NEWLINE: &[u8] = b"\r\n";
+		const NOT_VALUE: &str = "field value must consist of visible characters, spaces or tabs";
+		let (limits, errors) = (&config.limits, ErrorContext{ bytes });
+		
+		// Find the header end and check the header size
+		let too_large = || errors.at(
+			limits.max_header_len, HttpError::HeaderTooLarge, Component::Header,
+			"header exceeds the maximum size"
+		);
+		let header_len = match config.header_len(bytes) {
+			Some(header_len) => header_len,
+			None if bytes.len() > limits.max_header_len => Err(too_large())?,
+			None => Err(errors.at(
+				bytes.len(), HttpError::TruncatedData, Component::Header,
+				"header is not terminated by an empty line"
+			))?
+		};
+		if header_len > limits.max_header_len {
+			Err(too_large())?
+		}
+		
+		// Split the header into lines and remove the terminating empty line
+		let mut lines: Vec<&'a[u8]> = match config.allow_bare_lf {
+			true => bytes[..header_len].split(|b| *b == b'\n')
+				.map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+				.collect(),
+			false => bytes[..header_len].split_pat(&NEWLINE).collect()
+		};
+		lines.truncate(lines.len() - 2);
+		let mut lines = lines.into_iter();
+		
+		// Parse status line
+		let line = lines.next().ok_or_else(|| errors.at(
+			0, HttpError::ProtocolViolation, Component::StatusLine, "missing status line"
+		))?;
+		let status_line = Self::parse_status_line(line, config, &errors)?;
+		
+		// Parse header fields
+		let mut fields: Vec<(&'a[u8], Cow<'a, [u8]>)> = Vec::new();
+		let mut field_len = 0;
+		for line in lines {
+			// Check the limits
+			let is_obs_fold = line.starts_with(b" ") || line.starts_with(b"\t");
+			if !is_obs_fold && fields.len() >= limits.max_fields {
+				Err(errors.of(
+					line, HttpError::TooManyFields, Component::Field,
+					"header exceeds the maximum amount of fields"
+				))?
+			}
+			field_len = match is_obs_fold {
+				true => field_len + line.len(),
+				false => line.len()
+			};
+			if field_len > limits.max_field_line_len {
+				Err(errors.of(
+					line, HttpError::FieldLineTooLong, Component::Field,
+					"field line exceeds the maximum length"
+				))?
+			}
+			
+			// Unfold obsolete line folding into the previous field value
+			if is_obs_fold {
+				let value = match fields.last_mut() {
+					Some((_, value)) if config.unfold_obs_fold => value,
+					_ => Err(errors.of(
+						line, HttpError::ProtocolViolation, Component::Field,
+						"obsolete line folding is not allowed"
+					))?
+				};
+				let continuation = errors.validate::<FieldValue>(
+					line.trim_ows(), Component::FieldValue, NOT_VALUE
+				)?;
+				if !continuation.is_empty() {
+					let value = value.to_mut();
+					if !value.is_empty() {
+						value.push(b' ');
+					}
+					value.extend_from_slice(continuation);
+				}
+				continue
+			}
+			
+			// Split and validate the field
+			let (key, value) = Self::parse_field(line, &errors)?;
+			let value = errors.validate::<FieldValue>(value, Component::FieldValue, NOT_VALUE)?;
+			fields.push((key, Cow::Borrowed(value)));
+		}
+		Ok(Self{ status_line, fields })
+	}
+	/// Parses and validates the status `line`
+	fn parse_status_line(line: &'a[u8], config: &ParserConfig, errors: &ErrorContext<'a>)
+		-> Result<StatusLine<'a>, ParseError>
+	{
+		const SPACE: &[u8] = b" ";
+		const RESPONSE_PREFIX: &[u8] = b"HTTP/";
+		const NOT_ASCII: &str = "status line must consist of printable ASCII characters";
+		const NOT_REASON: &str = "reason phrase must consist of visible characters, spaces or tabs";
+		
+		// Split the line and name the components depending on whether it is a status line
+		let status_line = line.trim_ows().splitn_pat(3, &SPACE)
+			.collect_exact(3).ok_or_else(|| errors.of(
+				line, HttpError::ProtocolViolation, Component::StatusLine,
+				"status line must consist of three space-separated parts"
+			))?;
+		let components = match status_line[0].starts_with(RESPONSE_PREFIX) {
+			true => [Component::Version, Component::Status, Component::Reason],
+			false => [Component::Method, Component::Uri, Component::Version]
+		};
+		if status_line[1].len() > config.limits.max_uri_len {
+			Err(errors.of(
+				status_line[1], HttpError::UriTooLong, components[1],
+				"request target exceeds the maximum length"
+			))?
+		}
+		
+		// Validate the components
+		let validated = (
+			errors.validate::<Ascii>(status_line[0], components[0], NOT_ASCII)?,
+			errors.validate::<Ascii>(status_line[1], components[1], NOT_ASCII)?,
+			match components[2] {
+				Component::Reason =>
+					errors.validate::<FieldValue>(status_line[2], Component::Reason, NOT_REASON)?,
+				component => {
+					let version = errors.validate::<Ascii>(status_line[2], component, NOT_ASCII)?;
+					errors.validate::<FieldValue>(version, component, NOT_ASCII)?
+				}
+			}
+		);
+		
+		// Validate the version
+		let version = match components[0] {
+			Component::Version => validated.0,
+			_ => validated.2
+		};
+		Version::try_from(version).map_err(|kind| match kind {
+			HttpError::UnsupportedVersion => errors.of(
+				version, kind, Component::Version, "HTTP version is not supported"
+			),
+			kind => errors.of(version, kind, Component::Version, "version must match HTTP/DIGIT.DIGIT")
+		})?;
+		Ok(validated)
+	}
+	/// Splits a field `line` into the validated key and the trimmed value
+	fn parse_field(line: &'a[u8], errors: &ErrorContext<'a>)
+		-> Result<(&'a[u8], &'a[u8]), ParseError>
+	{
+		const SEPARATOR: &[u8] = b":";
+		
+		// Split the line and ensure that there is no whitespace between the key and the colon
+		let key_value = line.splitn_pat(2, &SEPARATOR)
+			.collect_min(2).ok_or_else(|| errors.of(
+				line, HttpError::ProtocolViolation, Component::Field,
+				"field line must contain a colon"
+			))?;
+		let key_len = key_value[0].trim_end_matches(|b| *b == b' ' || *b == b'\t').len();
+		if key_len > 0 && key_len < key_value[0].len() {
+			Err(errors.of(
+				&key_value[0][key_len..], HttpError::WhitespaceBeforeColon,
+				Component::FieldName, "field name must not be followed by whitespace"
+			))?
+		}
+		
+		// Validate the key
+		let key = errors.validate::<HeaderFieldKey>(
+			key_value[0], Component::FieldName, "field name must be a non-empty token"
+		)?;
+		Ok((key, key_value[1].trim_ows()))
+	}
+	
+	/// The header status line
+	pub fn status_line(&self) -> (&'a[u8], &'a[u8], &'a[u8]) {
+		self.status_line
+	}
+	/// Gets the first field for `key` if any (the comparison is case-insensitive)
+	pub fn field(&self, key: impl AsRef<[u8]>) -> Option<&[u8]> {
+		let key = key.as_ref();
+		self.fields.iter()
+			.find(|(k, _)| k.eq_ignore_ascii_case(key))
+			.map(|(_, v)| v.as_ref())
+	}
+	/// The header fields as `(key, value)`-pairs in their original order
+	///
+	/// _Note: a value is only owned if it has been unfolded from obsolete line folding._
+	pub fn fields(&self) -> &[(&'a[u8], Cow<'a, [u8]>)] {
+		&self.fields
+	}
+	
+	/// Copies the borrowed header into an owned `Header`
+	pub fn to_owned(&self) -> Header {
+		// All parts have been validated during parsing so the conversions cannot fail
+		const VALIDATED: &str = "Should never fail because the data has already been validated";
+		let status_line = (
+			Data::try_from(self.status_line.0).expect(VALIDATED),
+			Data::try_from(self.status_line.1).expect(VALIDATED),
+			Data::try_from(self.status_line.2).expect(VALIDATED)
+		);
+		let mut fields = HeaderFields::new();
+		for (k, v) in self.fields.iter() {
+			let (k, v) = (Data::try_from(*k), Data::try_from(v.as_ref()));
+			fields.append(k.expect(VALIDATED), v.expect(VALIDATED));
+		}
+		Header{ status_line, fields }
+	}
+}
+
+
+/// Implements repetitive borrowed header functions
+macro_rules! repetitive_header_ref_fns {
+	($struct:ident) => {
+		impl<'a> $struct<'a> {
+			/// Gets the first field for `key` if any (the comparison is case-insensitive)
+			pub fn field(&self, key: impl AsRef<[u8]>) -> Option<&[u8]> {
+				self.header.field(key)
+			}
+			/// The header fields as `(key, value)`-pairs in their original order
+			pub fn fields(&self) -> &[(&'a[u8], Cow<'a, [u8]>)] {
+				self.header.fields()
+			}
+		}
+		impl<'a> AsRef<HeaderRef<'a>> for $struct<'a> {
+			fn as_ref(&self) -> &HeaderRef<'a> {
+				&self.header
+			}
+		}
+		impl<'a> From<$struct<'a>> for HeaderRef<'a> {
+			fn from(s: $struct<'a>) -> Self {
+				s.header
+			}
+		}
+	};
+}
+
+
+/// A borrowed HTTP request header
+#[derive(Debug, Clone)]
+pub struct RequestHeaderRef<'a> {
+	header: HeaderRef<'a>
+}
+impl<'a> RequestHeaderRef<'a> {
+	/// The request method
+	pub fn method(&self) -> &'a[u8] {
+		self.header.status_line.0
+	}
+	/// The requested URI
+	pub fn uri(&self) -> &'a[u8] {
+		self.header.status_line.1
+	}
+	/// The HTTP version
+	pub fn version(&self) -> &'a[u8] {
+		self.header.status_line.2
+	}
+	
+	/// Copies the borrowed header into an owned `RequestHeader`
+	pub fn to_owned(&self) -> RequestHeader {
+		self.header.to_owned().try_into()
+			.expect("Should never fail because the data has already been validated")
+	}
+}
+repetitive_header_ref_fns!(RequestHeaderRef);
+impl<'a> TryFrom<HeaderRef<'a>> for RequestHeaderRef<'a> {
+	type Error = HttpError;
+	/// Tries to create a `RequestHeaderRef` from a `HeaderRef`
+	fn try_from(header: HeaderRef<'a>) -> Result<Self, Self::Error> {
+		let method = Method::try_from(header.status_line.0)?;
+		RequestTarget::parse(&method, header.status_line.1)?;
+		Ok(Self{ header })
+	}
+}
+
+
+/// A borrowed HTTP response header
+#[derive(Debug, Clone)]
+pub struct ResponseHeaderRef<'a> {
+	header: HeaderRef<'a>,
+	status: StatusCode
+}
+impl<'a> ResponseHeaderRef<'a> {
+	/// The HTTP version
+	pub fn version(&self) -> &'a[u8] {
+		self.header.status_line.0
+	}
+	/// The status code
+	pub fn status(&self) -> StatusCode {
+		self.status
+	}
+	/// The status reason
+	pub fn reason(&self) -> &'a[u8] {
+		self.header.status_line.2
+	}
+	
+	/// Copies the borrowed header into an owned `ResponseHeader`
+	pub fn to_owned(&self) -> ResponseHeader {
+		self.header.to_owned().try_into()
+			.expect("Should never fail because the data has already been validated")
+	}
+}
+repetitive_header_ref_fns!(ResponseHeaderRef);
+impl<'a> TryFrom<HeaderRef<'a>> for ResponseHeaderRef<'a> {
+	type Error = HttpError;
+	/// Tries to create a `ResponseHeaderRef` from a `HeaderRef`
+	fn try_from(header: HeaderRef<'a>) -> Result<Self, Self::Error> {
+		validate::<FieldValue>(header.status_line.2)?;
+		let status = StatusCode::try_from(header.status_line.1)?;
+		Ok(Self{ header, status })
+	}
+}
\ No newline at end of file
@@ -0,0 +1,135 @@
+use crate::{
+	HttpError,
+	data::{ Data, encodings::Token }
+};
+use std::{
+	str,
+	convert::TryFrom,
+	hash::{ Hash, Hasher },
+	fmt::{ self, Display, Formatter }
+};
+
+
+/// A HTTP request method according to [RFC 7231](https://tools.ietf.org/html/rfc7231#section-4)
+/// and [RFC 5789](https://tools.ietf.org/html/rfc5789)
+///
+/// Methods are case-sensitive; a method that is not one of the standard methods is stored as
+/// validated extension token.
+#[derive(Debug, Clone)]
+pub enum Method {
+	/// `GET`
+	Get,
+	/// `HEAD`
+	Head,
+	/// `POST`
+	Post,
+	/// `PUT`
+	Put,
+	/// `DELETE`
+	Delete,
+	/// `CONNECT`
+	Connect,
+	/// `OPTIONS`
+	Options,
+	/// `TRACE`
+	Trace,
+	/// `PATCH`
+	Patch,
+	/// An extension method
+	Extension(Data<Token>)
+}
+impl Method {
+	/// The method as bytes
+	pub fn as_bytes(&self) -> &[u8] {
+		match self {
+			Method::Get => b"GET",
+			Method::Head => b"HEAD",
+			Method::Post => b"POST",
+			Method::Put => b"PUT",
+			Method::Delete => b"DELETE",
+			Method::Connect => b"CONNECT",
+			Method::Options => b"OPTIONS",
+			Method::Trace => b"TRACE",
+			Method::Patch => b"PATCH",
+			Method::Extension(token) => token
+		}
+	}
+	/// The method as string
+	pub fn as_str(&self) -> &str {
+		str::from_utf8(self.as_bytes()).expect("Should never fail because tokens are ASCII")
+	}
+	
+	/// Checks if the method is [safe](https://tools.ietf.org/html/rfc7231#section-4.2.1) (i.e.
+	/// read-only)
+	///
+	/// _Note: extension methods are never considered safe._
+	pub fn is_safe(&self) -> bool {
+		matches!(self, Method::Get | Method::Head | Method::Options | Method::Trace)
+	}
+	/// Checks if the method is [idempotent](https://tools.ietf.org/html/rfc7231#section-4.2.2)
+	/// (i.e. a request can be retried automatically)
+	///
+	/// _Note: extension methods are never considered idempotent._
+	pub fn is_idempotent(&self) -> bool {
+		self.is_safe() || matches!(self, Method::Put | Method::Delete)
+	}
+	/// Checks if responses to the method are
+	/// [cacheable](https://tools.ietf.org/html/rfc7231#section-4.2.3)
+	///
+	/// _Note: responses to `POST` are only cacheable if they contain explicit freshness
+	/// information; extension methods are never considered cacheable._
+	pub fn is_cacheable(&self) -> bool {
+		matches!(self, Method::Get | Method::Head | Method::Post)
+	}
+}
+impl Display for Method {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+impl PartialEq for Method {
+	fn eq(&self, other: &Self) -> bool {
+		self.as_bytes() == other.as_bytes()
+	}
+}
+impl Eq for Method {}
+impl Hash for Method {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.as_bytes().hash(state)
+	}
+}
+impl PartialEq<str> for Method {
+	fn eq(&self, other: &str) -> bool {
+		self.as_bytes() == other.as_bytes()
+	}
+}
+impl PartialEq<Method> for str {
+	fn eq(&self, other: &Method) -> bool {
+		self.as_bytes() == other.as_bytes()
+	}
+}
+impl TryFrom<&[u8]> for Method {
+	type Error = HttpError;
+	/// Tries to create a method from `bytes`
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		Ok(match bytes {
+			b"GET" => Method::Get,
+			b"HEAD" => Method::Head,
+			b"POST" => Method::Post,
+			b"PUT" => Method::Put,
+			b"DELETE" => Method::Delete,
+			b"CONNECT" => Method::Connect,
+			b"OPTIONS" => Method::Options,
+			b"TRACE" => Method::Trace,
+			b"PATCH" => Method::Patch,
+			bytes => Method::Extension(Data::try_from(bytes)?)
+		})
+	}
+}
+impl TryFrom<&str> for Method {
+	type Error = HttpError;
+	/// Tries to create a method from `string`
+	fn try_from(string: &str) -> Result<Self, Self::Error> {
+		Self::try_from(string.as_bytes())
+	}
+}
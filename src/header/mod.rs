@@ -1,2 +1,12 @@
 pub mod builders;
-pub mod header;
\ No newline at end of file
+pub mod config;
+pub mod fields;
+pub mod hardening;
+#[allow(clippy::module_inception)]
+pub mod header;
+pub mod header_ref;
+pub mod method;
+pub mod parser;
+pub mod status;
+pub mod target;
+pub mod version;
\ No newline at end of file
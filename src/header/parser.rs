@@ -0,0 +1,86 @@
+use crate::{ ParseError, Header, ParserConfig };
+
+
+/// The result of feeding a chunk into a `HeaderParser`
+#[derive(Debug, Clone)]
+pub enum ParseStatus {
+	/// The header is incomplete and more data is needed
+	Partial,
+	/// The header is complete
+	///
+	/// Contains the parsed header and the amount of bytes consumed from the *last* chunk; all
+	/// bytes after `consumed_len` belong to the body.
+	Complete(Header, usize)
+}
+
+
+/// An incremental, resumable header parser
+///
+/// The parser buffers the chunks as they arrive and remembers how far it has already scanned, so
+/// feeding a new chunk does not rescan the previously buffered data.
+#[derive(Debug, Clone)]
+pub struct HeaderParser {
+	buf: Vec<u8>,
+	scanned: usize,
+	config: ParserConfig
+}
+impl HeaderParser {
+	/// Creates a new incremental parser that does not enforce any limits
+	pub fn new() -> Self {
+		Self::with_config(ParserConfig::unlimited())
+	}
+	/// Creates a new incremental parser using `config`
+	///
+	/// _Note: the maximum header size is already enforced on partial data, so that an oversized
+	/// header is rejected as early as possible._
+	pub fn with_config(config: ParserConfig) -> Self {
+		Self{ buf: Vec::new(), scanned: 0, config }
+	}
+	
+	/// Feeds the next `chunk` into the parser
+	///
+	/// Returns either `ParseStatus::Partial` if the header end has not been seen yet or
+	/// `ParseStatus::Complete(header, consumed_len)`. After a complete header or a parsing error,
+	/// the parser is reset and can be used for the next header. The offsets of a `ParseError` are
+	/// relative to the beginning of the header.
+	pub fn push(&mut self, chunk: &[u8]) -> Result<ParseStatus, ParseError> {
+		const MAX_END_LEN: usize = b"\r\n\r\n".len();
+		
+		// Append the chunk and search the header end starting at the first unscanned position
+		let buffered = self.buf.len();
+		self.buf.extend_from_slice(chunk);
+		let end = match self.config.header_len(&self.buf[self.scanned..]) {
+			Some(header_len) => self.scanned + header_len,
+			None if self.buf.len() > self.config.limits.max_header_len => {
+				// Let the parser create the appropriate error for the oversized data
+				let error = Header::parse_with(&self.buf, &self.config).unwrap_err();
+				self.reset();
+				Err(error)?
+			},
+			None => {
+				// Remember the position; the last bytes may be the beginning of a split header end
+				self.scanned = self.buf.len().saturating_sub(MAX_END_LEN - 1);
+				return Ok(ParseStatus::Partial)
+			}
+		};
+		
+		// Parse the header and reset the state
+		let header = Header::parse_with(&self.buf[..end], &self.config);
+		self.reset();
+		Ok(ParseStatus::Complete(header?, end - buffered))
+	}
+	/// The amount of bytes currently buffered
+	pub fn buffered(&self) -> usize {
+		self.buf.len()
+	}
+	/// Resets the parser and discards all buffered data
+	pub fn reset(&mut self) {
+		self.buf.clear();
+		self.scanned = 0;
+	}
+}
+impl Default for HeaderParser {
+	fn default() -> Self {
+		Self::new()
+	}
+}
\ No newline at end of file
@@ -0,0 +1,179 @@
+use crate::HttpError;
+use std::{
+	convert::TryFrom,
+	fmt::{ self, Display, Formatter }
+};
+
+
+/// Defines the status code constants and their reason phrases
+macro_rules! status_codes {
+	($(($code:expr, $name:ident, $reason:expr)),* $(,)?) => {
+		impl StatusCode {
+			$(
+				#[doc = concat!("`", stringify!($code), " ", $reason, "`")]
+				pub const $name: StatusCode = StatusCode($code);
+			)*
+			
+			/// The reason phrase registered in the
+			/// [IANA registry](https://www.iana.org/assignments/http-status-codes) if any
+			pub fn canonical_reason(&self) -> Option<&'static str> {
+				match self.0 {
+					$($code => Some($reason),)*
+					_ => None
+				}
+			}
+		}
+	};
+}
+
+
+/// A HTTP status code according to [RFC 7231](https://tools.ietf.org/html/rfc7231#section-6)
+///
+/// A status code is a three-digit integer within `100..=599`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct StatusCode(u16);
+impl StatusCode {
+	/// The status code as integer
+	pub fn as_u16(&self) -> u16 {
+		self.0
+	}
+	
+	/// Checks if the status code is informational (`1xx`)
+	pub fn is_informational(&self) -> bool {
+		(100..200).contains(&self.0)
+	}
+	/// Checks if the status code indicates success (`2xx`)
+	pub fn is_success(&self) -> bool {
+		(200..300).contains(&self.0)
+	}
+	/// Checks if the status code is a redirection (`3xx`)
+	pub fn is_redirection(&self) -> bool {
+		(300..400).contains(&self.0)
+	}
+	/// Checks if the status code indicates a client error (`4xx`)
+	pub fn is_client_error(&self) -> bool {
+		(400..500).contains(&self.0)
+	}
+	/// Checks if the status code indicates a server error (`5xx`)
+	pub fn is_server_error(&self) -> bool {
+		(500..600).contains(&self.0)
+	}
+}
+status_codes! {
+	(100, CONTINUE, "Continue"),
+	(101, SWITCHING_PROTOCOLS, "Switching Protocols"),
+	(102, PROCESSING, "Processing"),
+	(103, EARLY_HINTS, "Early Hints"),
+	(200, OK, "OK"),
+	(201, CREATED, "Created"),
+	(202, ACCEPTED, "Accepted"),
+	(203, NON_AUTHORITATIVE_INFORMATION, "Non-Authoritative Information"),
+	(204, NO_CONTENT, "No Content"),
+	(205, RESET_CONTENT, "Reset Content"),
+	(206, PARTIAL_CONTENT, "Partial Content"),
+	(207, MULTI_STATUS, "Multi-Status"),
+	(208, ALREADY_REPORTED, "Already Reported"),
+	(226, IM_USED, "IM Used"),
+	(300, MULTIPLE_CHOICES, "Multiple Choices"),
+	(301, MOVED_PERMANENTLY, "Moved Permanently"),
+	(302, FOUND, "Found"),
+	(303, SEE_OTHER, "See Other"),
+	(304, NOT_MODIFIED, "Not Modified"),
+	(305, USE_PROXY, "Use Proxy"),
+	(307, TEMPORARY_REDIRECT, "Temporary Redirect"),
+	(308, PERMANENT_REDIRECT, "Permanent Redirect"),
+	(400, BAD_REQUEST, "Bad Request"),
+	(401, UNAUTHORIZED, "Unauthorized"),
+	(402, PAYMENT_REQUIRED, "Payment Required"),
+	(403, FORBIDDEN, "Forbidden"),
+	(404, NOT_FOUND, "Not Found"),
+	(405, METHOD_NOT_ALLOWED, "Method Not Allowed"),
+	(406, NOT_ACCEPTABLE, "Not Acceptable"),
+	(407, PROXY_AUTHENTICATION_REQUIRED, "Proxy Authentication Required"),
+	(408, REQUEST_TIMEOUT, "Request Timeout"),
+	(409, CONFLICT, "Conflict"),
+	(410, GONE, "Gone"),
+	(411, LENGTH_REQUIRED, "Length Required"),
+	(412, PRECONDITION_FAILED, "Precondition Failed"),
+	(413, CONTENT_TOO_LARGE, "Content Too Large"),
+	(414, URI_TOO_LONG, "URI Too Long"),
+	(415, UNSUPPORTED_MEDIA_TYPE, "Unsupported Media Type"),
+	(416, RANGE_NOT_SATISFIABLE, "Range Not Satisfiable"),
+	(417, EXPECTATION_FAILED, "Expectation Failed"),
+	(421, MISDIRECTED_REQUEST, "Misdirected Request"),
+	(422, UNPROCESSABLE_CONTENT, "Unprocessable Content"),
+	(423, LOCKED, "Locked"),
+	(424, FAILED_DEPENDENCY, "Failed Dependency"),
+	(425, TOO_EARLY, "Too Early"),
+	(426, UPGRADE_REQUIRED, "Upgrade Required"),
+	(428, PRECONDITION_REQUIRED, "Precondition Required"),
+	(429, TOO_MANY_REQUESTS, "Too Many Requests"),
+	(431, REQUEST_HEADER_FIELDS_TOO_LARGE, "Request Header Fields Too Large"),
+	(451, UNAVAILABLE_FOR_LEGAL_REASONS, "Unavailable For Legal Reasons"),
+	(500, INTERNAL_SERVER_ERROR, "Internal Server Error"),
+	(501, NOT_IMPLEMENTED, "Not Implemented"),
+	(502, BAD_GATEWAY, "Bad Gateway"),
+	(503, SERVICE_UNAVAILABLE, "Service Unavailable"),
+	(504, GATEWAY_TIMEOUT, "Gateway Timeout"),
+	(505, HTTP_VERSION_NOT_SUPPORTED, "HTTP Version Not Supported"),
+	(506, VARIANT_ALSO_NEGOTIATES, "Variant Also Negotiates"),
+	(507, INSUFFICIENT_STORAGE, "Insufficient Storage"),
+	(508, LOOP_DETECTED, "Loop Detected"),
+	(511, NETWORK_AUTHENTICATION_REQUIRED, "Network Authentication Required")
+}
+impl Display for StatusCode {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+impl PartialEq<u16> for StatusCode {
+	fn eq(&self, other: &u16) -> bool {
+		self.0 == *other
+	}
+}
+impl PartialEq<StatusCode> for u16 {
+	fn eq(&self, other: &StatusCode) -> bool {
+		*self == other.0
+	}
+}
+impl From<StatusCode> for u16 {
+	fn from(code: StatusCode) -> Self {
+		code.0
+	}
+}
+impl TryFrom<u16> for StatusCode {
+	type Error = HttpError;
+	/// Tries to create a status code from `code`
+	///
+	/// Fails with `HttpError::ProtocolViolation` if `code` is not within `100..=599`.
+	fn try_from(code: u16) -> Result<Self, Self::Error> {
+		match code {
+			100..=599 => Ok(Self(code)),
+			_ => Err(HttpError::ProtocolViolation)
+		}
+	}
+}
+impl TryFrom<&[u8]> for StatusCode {
+	type Error = HttpError;
+	/// Tries to create a status code from exactly three ASCII digits
+	///
+	/// Fails with `HttpError::InvalidEncoding` if `bytes` contain non-digits or with
+	/// `HttpError::ProtocolViolation` if `bytes` are not a three-digit code within `100..=599`.
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		match bytes {
+			_ if !bytes.iter().all(u8::is_ascii_digit) => Err(HttpError::InvalidEncoding),
+			[_, _, _] => {
+				let code = bytes.iter().fold(0, |code, digit| code * 10 + u16::from(digit - b'0'));
+				Self::try_from(code)
+			},
+			_ => Err(HttpError::ProtocolViolation)
+		}
+	}
+}
+impl TryFrom<&str> for StatusCode {
+	type Error = HttpError;
+	/// Tries to create a status code from exactly three ASCII digits
+	fn try_from(string: &str) -> Result<Self, Self::Error> {
+		Self::try_from(string.as_bytes())
+	}
+}
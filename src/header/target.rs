@@ -0,0 +1,103 @@
+use crate::{
+	HttpError, Method,
+	data::{ Data, encodings::Uri }
+};
+use std::{
+	str,
+	convert::TryFrom,
+	fmt::{ self, Display, Formatter }
+};
+
+
+/// A request target according to [RFC 7230](https://tools.ietf.org/html/rfc7230#section-5.3)
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum RequestTarget {
+	/// An absolute path with an optional query (e.g. `/where?q=now`)
+	Origin(Data<Uri>),
+	/// An absolute URI (e.g. `http://www.example.org/pub/WWW/TheProject.html`), usually sent to
+	/// proxies
+	Absolute(Data<Uri>),
+	/// The authority of the destination (e.g. `www.example.com:80`); only used with `CONNECT`
+	Authority(Data<Uri>),
+	/// The asterisk `*`; only used with a server-wide `OPTIONS`
+	Asterisk
+}
+impl RequestTarget {
+	/// Parses `target` and validates its form against `method`
+	///
+	/// Fails with `HttpError::InvalidEncoding` if `target` contains non-URI characters or with
+	/// `HttpError::ProtocolViolation` if `target` is malformed or its form is not allowed for
+	/// `method`.
+	pub fn parse(method: &Method, target: &[u8]) -> Result<Self, HttpError> {
+		let uri: Data<Uri> = Data::try_from(target)?;
+		let target = match (method, target) {
+			(Method::Connect, _) if Self::is_authority(target) => RequestTarget::Authority(uri),
+			(Method::Connect, _) => Err(HttpError::ProtocolViolation)?,
+			(Method::Options, b"*") => RequestTarget::Asterisk,
+			(_, [b'/', ..]) if Self::is_origin(target) => RequestTarget::Origin(uri),
+			(_, _) if Self::is_absolute(target) => RequestTarget::Absolute(uri),
+			_ => Err(HttpError::ProtocolViolation)?
+		};
+		Ok(target)
+	}
+	
+	/// The request target as bytes
+	pub fn as_bytes(&self) -> &[u8] {
+		match self {
+			RequestTarget::Origin(uri) => uri,
+			RequestTarget::Absolute(uri) => uri,
+			RequestTarget::Authority(uri) => uri,
+			RequestTarget::Asterisk => b"*"
+		}
+	}
+	/// The request target as string
+	pub fn as_str(&self) -> &str {
+		str::from_utf8(self.as_bytes()).expect("Should never fail because URIs are ASCII")
+	}
+	
+	/// Checks if `target` is a valid
+	/// [`origin-form`](https://tools.ietf.org/html/rfc7230#section-5.3.1)
+	fn is_origin(target: &[u8]) -> bool {
+		// The path must start with a slash and must not contain IP-literal brackets; neither the
+		// path nor the query must contain a fragment
+		target.starts_with(b"/") && !target.iter().any(|b| b"#[]".contains(b))
+	}
+	/// Checks if `target` is a valid
+	/// [`absolute-form`](https://tools.ietf.org/html/rfc7230#section-5.3.2)
+	fn is_absolute(target: &[u8]) -> bool {
+		// Split the scheme
+		let scheme_len = match target.iter().position(|b| *b == b':') {
+			Some(scheme_len) => scheme_len,
+			None => return false
+		};
+		let scheme = &target[..scheme_len];
+		
+		// Validate the scheme and ensure that there is no fragment
+		let scheme_is_valid = scheme.first().map(u8::is_ascii_alphabetic).unwrap_or(false)
+			&& scheme.iter().all(|b| b.is_ascii_alphanumeric() || b"+-.".contains(b));
+		scheme_is_valid && !target.contains(&b'#')
+	}
+	/// Checks if `target` is a valid
+	/// [`authority-form`](https://tools.ietf.org/html/rfc7231#section-4.3.6) (i.e. `host:port`)
+	fn is_authority(target: &[u8]) -> bool {
+		// Split the port
+		let port_start = match target.iter().rposition(|b| *b == b':') {
+			Some(colon) => colon + 1,
+			None => return false
+		};
+		let (host, port) = (&target[..port_start - 1], &target[port_start..]);
+		
+		// Validate the host and the port
+		let host_is_valid = match host {
+			[] => false,
+			[b'[', ip_literal @ .., b']'] => !ip_literal.iter().any(|b| b"[]/?#@".contains(b)),
+			host => !host.iter().any(|b| b":[]/?#@".contains(b))
+		};
+		host_is_valid && !port.is_empty() && port.iter().all(u8::is_ascii_digit)
+	}
+}
+impl Display for RequestTarget {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
@@ -0,0 +1,99 @@
+use crate::HttpError;
+use std::{
+	str,
+	convert::TryFrom,
+	fmt::{ self, Display, Formatter }
+};
+
+
+/// A HTTP version according to [RFC 7230](https://tools.ietf.org/html/rfc7230#section-2.6)
+///
+/// Versions are ordered, so that version-dependent behavior can be expressed as comparison (e.g.
+/// `version >= Version::Http11`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Version {
+	/// `HTTP/0.9`
+	Http09,
+	/// `HTTP/1.0`
+	Http10,
+	/// `HTTP/1.1`
+	Http11
+}
+impl Version {
+	/// The version as bytes
+	pub fn as_bytes(&self) -> &'static[u8] {
+		match self {
+			Version::Http09 => b"HTTP/0.9",
+			Version::Http10 => b"HTTP/1.0",
+			Version::Http11 => b"HTTP/1.1"
+		}
+	}
+	/// The version as string
+	pub fn as_str(&self) -> &'static str {
+		str::from_utf8(self.as_bytes()).expect("Should never fail because all versions are ASCII")
+	}
+	/// The major version number
+	pub fn major(&self) -> u8 {
+		self.as_bytes()[5] - b'0'
+	}
+	/// The minor version number
+	pub fn minor(&self) -> u8 {
+		self.as_bytes()[7] - b'0'
+	}
+	
+	/// Checks if connections are persistent unless `Connection: close` is sent (`HTTP/1.1`) or
+	/// closed unless `Connection: keep-alive` is sent (`HTTP/1.0` and older)
+	pub fn is_keep_alive_default(&self) -> bool {
+		*self >= Version::Http11
+	}
+	/// Checks if requests must contain a `Host` field according to
+	/// [RFC 7230](https://tools.ietf.org/html/rfc7230#section-5.4)
+	pub fn requires_host(&self) -> bool {
+		*self >= Version::Http11
+	}
+	/// Checks if the version supports the `Transfer-Encoding` field (and thus the chunked encoding)
+	pub fn supports_transfer_encoding(&self) -> bool {
+		*self >= Version::Http11
+	}
+}
+impl Display for Version {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+impl PartialEq<str> for Version {
+	fn eq(&self, other: &str) -> bool {
+		self.as_bytes() == other.as_bytes()
+	}
+}
+impl PartialEq<Version> for str {
+	fn eq(&self, other: &Version) -> bool {
+		self.as_bytes() == other.as_bytes()
+	}
+}
+impl TryFrom<&[u8]> for Version {
+	type Error = HttpError;
+	/// Tries to create a version from `bytes`
+	///
+	/// Fails with `HttpError::UnsupportedVersion` if `bytes` match the `HTTP/DIGIT.DIGIT` grammar
+	/// but denote an unknown version or with `HttpError::ProtocolViolation` if they don't match the
+	/// grammar at all.
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		match bytes {
+			b"HTTP/0.9" => Ok(Version::Http09),
+			b"HTTP/1.0" => Ok(Version::Http10),
+			b"HTTP/1.1" => Ok(Version::Http11),
+			[b'H', b'T', b'T', b'P', b'/', major, b'.', minor]
+				if major.is_ascii_digit() && minor.is_ascii_digit() =>
+				Err(HttpError::UnsupportedVersion),
+			_ => Err(HttpError::ProtocolViolation)
+		}
+	}
+}
+impl TryFrom<&str> for Version {
+	type Error = HttpError;
+	/// Tries to create a version from `string`
+	fn try_from(string: &str) -> Result<Self, Self::Error> {
+		Self::try_from(string.as_bytes())
+	}
+}
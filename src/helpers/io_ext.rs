@@ -1,22 +1,32 @@
+use crate::helpers::slice_ext::SliceExt;
 use std::io::{ self, Read, Write };
 
 
+/// Some data that has been read until a pattern and the bytes that have been read after it
+pub type DataOverread = (Vec<u8>, Vec<u8>);
+
+
 /// An extension of the `Write` trait
 pub trait ReadExt {
-	/// Reads until either `pat` is matched or `buf` is filled completely
+	/// Reads until either one of `pats` is matched or `buf` is filled completely
 	///
-	/// Returns either `Some(bytes_read)` if the pattern has been matched or `None` if `buf` has
-	/// been filled completely without matching the pattern.
-	fn read_until(&mut self, buf: &mut[u8], pat: impl AsRef<[u8]>)
+	/// Returns either `Some(bytes_read)` if a pattern has been matched or `None` if `buf` has
+	/// been filled completely without matching a pattern.
+	fn read_until(&mut self, buf: &mut[u8], pats: &[&[u8]])
 		-> Result<Option<usize>, io::Error>;
+	/// Reads chunk-wise until either one of `pats` is matched or `limit` bytes have been read
+	///
+	/// Returns either `Some((data, overread))` if a pattern has been matched, where `data` ends
+	/// with the pattern and `overread` contains all bytes that have been read after the pattern, or
+	/// `None` if `limit` has been reached or the source is exhausted without matching a pattern.
+	fn read_until_chunked(&mut self, limit: usize, pats: &[&[u8]])
+		-> Result<Option<DataOverread>, io::Error>;
 }
 impl<T: Read> ReadExt for T {
-	fn read_until(&mut self, buf: &mut[u8], pat: impl AsRef<[u8]>)
+	fn read_until(&mut self, buf: &mut[u8], pats: &[&[u8]])
 		-> Result<Option<usize>, io::Error>
 	{
-		let pat = pat.as_ref();
-
-		// Read the input byte-per-byte and check for the pattern
+		// Read the input byte-per-byte and check for the patterns
 		let mut pos = 0;
 		while pos < buf.len() {
 			// Read the next byte and adjust `pos`
@@ -28,13 +38,43 @@ impl<T: Read> ReadExt for T {
 				}
 			}
 			
-			// Check for pattern
-			if pos >= pat.len() && &buf[pos - pat.len() .. pos] == pat {
+			// Check for patterns
+			if pats.iter().any(|pat| buf[..pos].ends_with(pat)) {
 				return Ok(Some(pos))
 			}
 		}
 		Ok(None)
 	}
+	fn read_until_chunked(&mut self, limit: usize, pats: &[&[u8]])
+		-> Result<Option<DataOverread>, io::Error>
+	{
+		const CHUNK_SIZE: usize = 4096;
+		let max_pat_len = pats.iter().map(|pat| pat.len()).max().unwrap_or_default();
+		
+		// Read the input chunk-wise and check for the pattern
+		let (mut buf, mut scanned) = (Vec::new(), 0);
+		while buf.len() < limit {
+			// Read the next chunk
+			let filled = buf.len();
+			buf.resize(limit.min(filled + CHUNK_SIZE), 0);
+			match self.read(&mut buf[filled..]) {
+				Ok(0) => return Ok(None),
+				Ok(read) => buf.truncate(filled + read),
+				Err(e) => match e.kind() {
+					io::ErrorKind::Interrupted => buf.truncate(filled),
+					_ => Err(e)?
+				}
+			}
+			
+			// Check for patterns starting at the first unscanned position
+			if let Some(end) = buf[scanned..].find_end_any(pats) {
+				let overread = buf.split_off(scanned + end);
+				return Ok(Some((buf, overread)))
+			}
+			scanned = buf.len().saturating_sub(max_pat_len.saturating_sub(1));
+		}
+		Ok(None)
+	}
 }
 
 
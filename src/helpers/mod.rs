@@ -1,3 +1,4 @@
 pub mod io_ext;
 pub mod iter_ext;
+pub mod ordered_pairs;
 pub mod slice_ext;
@@ -0,0 +1,119 @@
+use std::{ slice, iter::FromIterator };
+
+
+/// An insertion-ordered multi-map that stores every `(key, value)`-pair separately
+///
+/// A key may occur multiple times; all pairs keep the order in which they were added.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OrderedPairs<K, V> {
+	pairs: Vec<(K, V)>
+}
+impl<K: PartialEq, V> OrderedPairs<K, V> {
+	/// Creates a new, empty pair store
+	pub fn new() -> Self {
+		Self{ pairs: Vec::new() }
+	}
+	
+	/// Gets the first value for `key` if any
+	pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V> where K: PartialEq<Q> {
+		self.get_all(key).next()
+	}
+	/// Gets a mutable reference to the first value for `key` if any
+	pub fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Option<&mut V> where K: PartialEq<Q> {
+		self.pairs.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v)
+	}
+	/// Returns an iterator over all values for `key` in the order they were added
+	pub fn get_all<'a, 'k, Q: ?Sized>(&'a self, key: &'k Q) -> GetAll<'a, 'k, K, V, Q>
+		where K: PartialEq<Q>
+	{
+		GetAll{ pairs: self.pairs.iter(), key }
+	}
+	/// Checks if there is at least one value for `key`
+	pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool where K: PartialEq<Q> {
+		self.pairs.iter().any(|(k, _)| k == key)
+	}
+	
+	/// Inserts `value` for `key` and replaces all existing values for `key`
+	///
+	/// The new pair takes the position of the first replaced pair or is appended if there is no
+	/// pair for `key` yet.
+	pub fn insert(&mut self, key: K, value: V) {
+		match self.pairs.iter().position(|(k, _)| k == &key) {
+			Some(index) => {
+				// Remove all subsequent pairs with the same key and replace the first pair
+				let tail: Vec<_> = self.pairs.drain(index + 1 ..)
+					.filter(|(k, _)| k != &key).collect();
+				self.pairs.extend(tail);
+				self.pairs[index] = (key, value);
+			},
+			None => self.pairs.push((key, value))
+		}
+	}
+	/// Appends `value` to the existing values for `key`
+	pub fn append(&mut self, key: K, value: V) {
+		self.pairs.push((key, value));
+	}
+	/// Removes all values for `key` and returns them
+	pub fn remove<Q: ?Sized>(&mut self, key: &Q) -> Vec<V> where K: PartialEq<Q> {
+		let (removed, pairs) = self.pairs.drain(..).partition(|(k, _)| k == key);
+		self.pairs = pairs;
+		removed.into_iter().map(|(_, v)| v).collect()
+	}
+	
+	/// The amount of pairs (i.e. each value of a multi-valued key counts separately)
+	pub fn len(&self) -> usize {
+		self.pairs.len()
+	}
+	/// Checks if there are no pairs
+	pub fn is_empty(&self) -> bool {
+		self.pairs.is_empty()
+	}
+	
+	/// Returns an iterator over all `(key, value)`-pairs in their order
+	pub fn iter(&self) -> Iter<'_, K, V> {
+		Iter{ pairs: self.pairs.iter() }
+	}
+	/// Returns an iterator over all `(key, value)`-pairs in their order with mutable values
+	pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+		self.pairs.iter_mut().map(|pair| (&pair.0, &mut pair.1))
+	}
+}
+impl<K: PartialEq, V> Default for OrderedPairs<K, V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+impl<K: PartialEq, V> FromIterator<(K, V)> for OrderedPairs<K, V> {
+	/// Collects the `(key, value)`-pairs by appending them
+	fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+		Self{ pairs: iter.into_iter().collect() }
+	}
+}
+
+
+/// An iterator over all values for a specific key
+#[derive(Debug)]
+pub struct GetAll<'a, 'k, K, V, Q: ?Sized> {
+	pairs: slice::Iter<'a, (K, V)>,
+	key: &'k Q
+}
+impl<'a, 'k, K: PartialEq<Q>, V, Q: ?Sized> Iterator for GetAll<'a, 'k, K, V, Q> {
+	type Item = &'a V;
+	fn next(&mut self) -> Option<Self::Item> {
+		let key = self.key;
+		self.pairs.find(|(k, _)| k == key).map(|(_, v)| v)
+	}
+}
+
+
+/// An iterator over all `(key, value)`-pairs
+#[derive(Debug)]
+pub struct Iter<'a, K, V> {
+	pairs: slice::Iter<'a, (K, V)>
+}
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+	type Item = (&'a K, &'a V);
+	fn next(&mut self) -> Option<Self::Item> {
+		self.pairs.next().map(|pair| (&pair.0, &pair.1))
+	}
+}
\ No newline at end of file
@@ -56,6 +56,8 @@ impl<'a, T: PartialEq + Debug> Debug for Splitter<'a, T> {
 pub trait SliceExt<'a, T: PartialEq> {
 	/// Gets the index of the *first* occurrence of `pat`
 	fn find(&'a self, pat: &'a dyn AsRef<[T]>) -> Option<usize>;
+	/// Gets the end index of the *first* completed occurrence of any pattern in `pats`
+	fn find_end_any(&'a self, pats: &'a [&'a [T]]) -> Option<usize>;
 	/// Splits the slice by `pat`
 	fn split_pat(&'a self, pat: &'a dyn AsRef<[T]>) -> Splitter<'a, T>;
 	/// Splits the slice `n` times by `pat`
@@ -67,8 +69,11 @@ impl<'a, T: PartialEq + Clone> SliceExt<'a, T> for [T] {
 		let end = self.len().checked_sub(pat.len())?;
 		(0 ..= end).find(|i| &self[*i .. *i + pat.len()] == pat)
 	}
+	fn find_end_any(&'a self, pats: &'a [&'a [T]]) -> Option<usize> {
+		pats.iter().filter_map(|pat| Some(self.find(pat)? + pat.len())).min()
+	}
 	fn split_pat(&'a self, pat: &'a dyn AsRef<[T]>) -> Splitter<'a, T> {
-		self.splitn_pat(usize::max_value(), pat)
+		self.splitn_pat(usize::MAX, pat)
 	}
 	fn splitn_pat(&'a self, n: usize, pat: &'a dyn AsRef<[T]>) -> Splitter<'a, T> {
 		Splitter{ data: self, pat: pat.as_ref(), remaining: n }
@@ -87,22 +92,6 @@ pub trait ByteSliceExt<'a> {
 		self.trim_start_matches(&pat).trim_end_matches(&pat)
 	}
 	
-	/// Returns a slice without the leading whitespace bytes
-	///
-	/// "Whitespace" is defined as an ASCII whitespace character:
-	/// U+0020 SPACE, U+0009 HORIZONTAL TAB, U+000A LINE FEED, U+000C FORM FEED, or U+000D CARRIAGE
-	/// RETURN.
-	fn trim_start(&'a self) -> &'a Self {
-		self.trim_start_matches(u8::is_ascii_whitespace)
-	}
-	/// Returns a slice without the trailing whitespace bytes
-	///
-	/// "Whitespace" is defined as an ASCII whitespace character:
-	/// U+0020 SPACE, U+0009 HORIZONTAL TAB, U+000A LINE FEED, U+000C FORM FEED, or U+000D CARRIAGE
-	/// RETURN.
-	fn trim_end(&'a self) -> &'a Self {
-		self.trim_end_matches(u8::is_ascii_whitespace)
-	}
 	/// Returns a slice without the leading and trailing whitespace bytes
 	///
 	/// "Whitespace" is defined as an ASCII whitespace character:
@@ -111,6 +100,12 @@ pub trait ByteSliceExt<'a> {
 	fn trim(&'a self) -> &'a Self {
 		self.trim_matches(u8::is_ascii_whitespace)
 	}
+	/// Returns a slice without the leading and trailing
+	/// [optional whitespace](https://tools.ietf.org/html/rfc7230#section-3.2.3) (i.e. U+0020 SPACE
+	/// or U+0009 HORIZONTAL TAB)
+	fn trim_ows(&'a self) -> &'a Self {
+		self.trim_matches(|b| *b == b' ' || *b == b'\t')
+	}
 }
 impl<'a> ByteSliceExt<'a> for [u8] {
 	fn trim_start_matches(&'a self, pat: impl Fn(&u8) -> bool) -> &'a Self {
@@ -1,7 +1,11 @@
 mod helpers;
 mod query_string;
+mod form;
+mod from_query;
 mod header;
 pub mod data;
+pub mod uri;
+pub mod typed;
 
 use std::{
 	error::Error,
@@ -9,9 +13,18 @@ use std::{
 };
 pub use crate::{
 	query_string::QueryString,
+	from_query::{ FromQuery, FromQueryValue },
 	header::{
 		builders::{ RequestBuilder, ResponseBuilder },
-		header::{ Header, RequestHeader, ResponseHeader }
+		config::{ ParseLimits, ParserConfig },
+		fields::HeaderFields,
+		header::{ Header, RequestHeader, ResponseHeader },
+		header_ref::{ HeaderRef, RequestHeaderRef, ResponseHeaderRef },
+		method::Method,
+		parser::{ HeaderParser, ParseStatus },
+		status::StatusCode,
+		target::RequestTarget,
+		version::Version
 	}
 };
 
@@ -19,14 +32,121 @@ pub use crate::{
 /// A `http_header` related error
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum HttpError {
+	/// The data does not conform to the expected encoding
 	InvalidEncoding,
+	/// The data is incomplete
 	TruncatedData,
+	/// The data violates the HTTP protocol
 	ProtocolViolation,
-	ApiMisuse
+	/// The API has been used incorrectly
+	ApiMisuse,
+	/// The header exceeds the maximum total size
+	HeaderTooLarge,
+	/// The header exceeds the maximum amount of fields
+	TooManyFields,
+	/// A header field line exceeds the maximum length
+	FieldLineTooLong,
+	/// The request target exceeds the maximum length
+	UriTooLong,
+	/// There is whitespace between a header field name and the colon
+	WhitespaceBeforeColon,
+	/// The request contains both a `Content-Length` and a `Transfer-Encoding`
+	AmbiguousFraming,
+	/// The request contains multiple, differing `Content-Length` values
+	ConflictingContentLength,
+	/// A `Content-Length` value is not a valid decimal integer
+	InvalidContentLength,
+	/// The `Transfer-Encoding` does not end with `chunked` or applies `chunked` more than once
+	ChunkedNotFinal,
+	/// The HTTP version is well-formed but not supported
+	UnsupportedVersion,
+	/// The (decoded) data is not valid UTF-8
+	InvalidUtf8,
+	/// A query parameter is missing or its value cannot be converted into the requested type
+	InvalidParameter
 }
 impl Display for HttpError {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		write!(f, "{:?}", self)
 	}
 }
-impl Error for HttpError {}
\ No newline at end of file
+impl Error for HttpError {}
+
+
+/// The header component a `ParseError` refers to
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Component {
+	/// The header as a whole
+	Header,
+	/// The request or status line as a whole
+	StatusLine,
+	/// The request method
+	Method,
+	/// The request target
+	Uri,
+	/// The HTTP version
+	Version,
+	/// The response status code
+	Status,
+	/// The response reason phrase
+	Reason,
+	/// A header field line as a whole
+	Field,
+	/// A header field name
+	FieldName,
+	/// A header field value
+	FieldValue
+}
+
+
+/// A detailed parse error
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseError {
+	/// The error kind
+	pub kind: HttpError,
+	/// The byte offset of the error within the parsed data
+	pub offset: usize,
+	/// The line number of the error (starting at `1`)
+	pub line: usize,
+	/// The component that failed
+	pub component: Component,
+	/// The rule that has been broken
+	pub reason: &'static str
+}
+impl Display for ParseError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(
+			f, "{:?} at line {}, byte {} ({:?}): {}",
+			self.kind, self.line, self.offset, self.component, self.reason
+		)
+	}
+}
+impl Error for ParseError {}
+impl From<ParseError> for HttpError {
+	fn from(error: ParseError) -> Self {
+		error.kind
+	}
+}
+
+
+/// A typed query parameter extraction error
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct QueryError {
+	/// The error kind
+	pub kind: HttpError,
+	/// The name of the parameter that failed
+	pub name: String,
+	/// The reason why the parameter failed
+	pub reason: &'static str
+}
+impl Display for QueryError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{:?} for query parameter \"{}\": {}", self.kind, self.name, self.reason)
+	}
+}
+impl Error for QueryError {}
+impl From<QueryError> for HttpError {
+	fn from(error: QueryError) -> Self {
+		error.kind
+	}
+}
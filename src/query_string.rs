@@ -1,54 +1,135 @@
 use crate::{
 	HttpError,
-	helpers::{ iter_ext::IterExt, slice_ext::SliceExt },
+	helpers::{ iter_ext::IterExt, ordered_pairs::{ self, OrderedPairs }, slice_ext::SliceExt },
 	data::{
 		Data,
 		encodings::{ Encoding, Uri, UriQuery }
 	}
 };
 use std::{
-	collections::HashMap, io::Write,
-	convert::{ TryFrom, TryInto }
+	str, io::Write,
+	iter::FromIterator,
+	convert::{ TryFrom, TryInto },
+	fmt::{ self, Display, Formatter }
 };
 use crate::helpers::slice_ext::ByteSliceExt;
 
 
+/// An iterator over all values for a specific key
+pub type GetAll<'a, 'k> =
+	ordered_pairs::GetAll<'a, 'k, Data<UriQuery>, Data<UriQuery>, Data<UriQuery>>;
+/// An iterator over all pairs of a `QueryString` instance
+pub type Iter<'a> = ordered_pairs::Iter<'a, Data<UriQuery>, Data<UriQuery>>;
+
+
 /// A [query string](https://tools.ietf.org/html/rfc3986#section-3.4)
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct QueryString(HashMap<Data<UriQuery>, Data<UriQuery>>);
+///
+/// A key may occur multiple times (e.g. `?tag=a&tag=b`); each occurrence is stored as separate
+/// pair and all pairs keep the order in which they were parsed or added so that the serialization
+/// is stable.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QueryString {
+	pairs: OrderedPairs<Data<UriQuery>, Data<UriQuery>>
+}
 impl QueryString {
-	/// Create a new `QueryUri` instance
+	/// Create a new, empty `QueryString` instance
 	pub fn new() -> Self {
-		Self(HashMap::new())
+		Self{ pairs: OrderedPairs::new() }
 	}
 	
-	/// Gets a reference to the field for `key`
-	pub fn field(&self, key: &Data<UriQuery>) -> Option<&Data<UriQuery>> {
-		self.0.get(&key)
+	/// Gets the first value for `key` if any
+	pub fn get(&self, key: &Data<UriQuery>) -> Option<&Data<UriQuery>> {
+		self.pairs.get(key)
 	}
-	/// Gets a mutable reference to field for `key`
-	pub fn field_mut(&mut self, key: &Data<UriQuery>) -> Option<&mut Data<UriQuery>> {
-		self.0.get_mut(&key)
+	/// Gets a mutable reference to the first value for `key` if any
+	pub fn get_mut(&mut self, key: &Data<UriQuery>) -> Option<&mut Data<UriQuery>> {
+		self.pairs.get_mut(key)
+	}
+	/// Returns an iterator over all values for `key` in the order they were added
+	pub fn get_all<'a, 'k>(&'a self, key: &'k Data<UriQuery>) -> GetAll<'a, 'k> {
+		self.pairs.get_all(key)
 	}
-	/// Inserts a new field with `key` and `value`
+	/// Checks if there is at least one value for `key`
+	pub fn contains_key(&self, key: &Data<UriQuery>) -> bool {
+		self.pairs.contains_key(key)
+	}
+	
+	/// Inserts `value` for `key` and replaces all existing values for `key`
+	///
+	/// The new pair takes the position of the first replaced pair or is appended if there is no
+	/// pair for `key` yet.
 	pub fn insert(&mut self, key: Data<UriQuery>, value: Data<UriQuery>) {
-		self.0.insert(key, value);
+		self.pairs.insert(key, value)
+	}
+	/// Appends `value` to the existing values for `key`
+	pub fn append(&mut self, key: Data<UriQuery>, value: Data<UriQuery>) {
+		self.pairs.append(key, value)
+	}
+	/// Removes all values for `key` and returns them
+	pub fn remove(&mut self, key: &Data<UriQuery>) -> Vec<Data<UriQuery>> {
+		self.pairs.remove(key)
+	}
+	
+	/// The amount of pairs (i.e. each value of a multi-valued key counts separately)
+	pub fn len(&self) -> usize {
+		self.pairs.len()
+	}
+	/// Checks if there are no pairs
+	pub fn is_empty(&self) -> bool {
+		self.pairs.is_empty()
 	}
 	
-	/// A reference to the fields
-	pub fn fields(&self) -> &HashMap<Data<UriQuery>, Data<UriQuery>> {
-		&self.0
+	/// Returns an iterator over all `(key, value)`-pairs in their order
+	pub fn iter(&self) -> Iter<'_> {
+		self.pairs.iter()
 	}
-	/// A mutable reference to the fields
-	pub fn fields_mut(&mut self) -> &mut HashMap<Data<UriQuery>, Data<UriQuery>> {
-		&mut self.0
+	/// Returns an iterator over all `(key, value)`-pairs in their order with mutable values
+	pub fn iter_mut(&mut self) -> impl Iterator<Item = (&Data<UriQuery>, &mut Data<UriQuery>)> {
+		self.pairs.iter_mut()
 	}
 	
+	/// Gets a reference to the first value for `key`
+	#[deprecated(note = "use `get` instead")]
+	pub fn field(&self, key: &Data<UriQuery>) -> Option<&Data<UriQuery>> {
+		self.get(key)
+	}
+	/// Gets a mutable reference to the first value for `key`
+	#[deprecated(note = "use `get_mut` instead")]
+	pub fn field_mut(&mut self, key: &Data<UriQuery>) -> Option<&mut Data<UriQuery>> {
+		self.get_mut(key)
+	}
+	/// Returns an iterator over all `(key, value)`-pairs in their order
+	#[deprecated(note = "use `iter` instead")]
+	pub fn fields(&self) -> Iter<'_> {
+		self.iter()
+	}
+	/// Returns an iterator over all `(key, value)`-pairs in their order with mutable values
+	#[deprecated(note = "use `iter_mut` instead")]
+	pub fn fields_mut(&mut self) -> impl Iterator<Item = (&Data<UriQuery>, &mut Data<UriQuery>)> {
+		self.iter_mut()
+	}
+}
+impl FromIterator<(Data<UriQuery>, Data<UriQuery>)> for QueryString {
+	/// Collects the `(key, value)`-pairs by appending them
+	fn from_iter<T>(iter: T) -> Self
+		where T: IntoIterator<Item = (Data<UriQuery>, Data<UriQuery>)>
+	{
+		Self{ pairs: iter.into_iter().collect() }
+	}
+}
+impl<'a> IntoIterator for &'a QueryString {
+	type Item = (&'a Data<UriQuery>, &'a Data<UriQuery>);
+	type IntoIter = Iter<'a>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+impl Display for QueryString {
 	/// Creates a query string from the stored key-value pairs
-	pub fn to_string(&self) -> String {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		// Create the query "string"
 		let mut query = vec![b'?'];
-		self.0.iter().for_each(|(k, v)| match v.len() {
+		self.iter().for_each(|(k, v)| match v.len() {
 			0 => write!(&mut query, "{}&", k).unwrap(),
 			_ => write!(&mut query, "{}={}&", k, v).unwrap()
 		});
@@ -56,7 +137,7 @@ impl QueryString {
 		// Remove last ampersand
 		let trimmed_len = query.trim_end_matches(|b| *b == b'&').len();
 		query.truncate(trimmed_len);
-		String::from_utf8(query).unwrap()
+		f.write_str(str::from_utf8(&query).unwrap())
 	}
 }
 impl TryFrom<Data<Uri>> for QueryString {
@@ -79,15 +160,15 @@ impl TryFrom<Data<Uri>> for QueryString {
 		}
 		
 		// Split the query into key-value parts
-		let mut query = HashMap::new();
+		let mut query = Self::new();
 		for kv in query_part.split_pat(b"&") {
 			let kv: Vec<&[u8]> = kv.splitn_pat(2, b"=").collect();
 			match kv.len() {
-				1 => query.insert(kv[0].try_into()?, b"".as_ref().try_into()?),
-				2 => query.insert(kv[0].try_into()?, kv[1].try_into()?),
+				1 => query.append(kv[0].try_into()?, b"".as_ref().try_into()?),
+				2 => query.append(kv[0].try_into()?, kv[1].try_into()?),
 				_ => unreachable!()
 			};
 		}
-		Ok(Self(query))
+		Ok(query)
 	}
 }
\ No newline at end of file
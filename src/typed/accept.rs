@@ -0,0 +1,121 @@
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ FieldValue, Token }
+	},
+	typed::{
+		MediaType, Quality, TypedHeader, Weighted, quote,
+		quality::{ parse_list, select }
+	}
+};
+use std::convert::TryFrom;
+
+
+/// A media range with its [accept-extensions](https://tools.ietf.org/html/rfc7231#section-5.3.2)
+/// (e.g. `text/html;level=1` with the extension `ext=x` in `text/html;level=1;q=0.5;ext=x`)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MediaRange {
+	/// The media range (may use the wildcards `*/*` or `type/*`)
+	pub media_type: MediaType,
+	/// The accept-extensions after the `q`-parameter
+	pub extensions: Vec<(Data<Token>, Data<FieldValue>)>
+}
+impl MediaRange {
+	/// The precedence of the media range; more specific ranges override less specific ones (i.e.
+	/// `*/*` < `type/*` < `type/subtype` < `type/subtype` with parameters)
+	fn specificity(&self) -> usize {
+		match (self.media_type.type_(), self.media_type.subtype()) {
+			("*", _) => 0,
+			(_, "*") => 1,
+			_ => 2 + self.media_type.params().len()
+		}
+	}
+}
+impl From<MediaType> for MediaRange {
+	fn from(media_type: MediaType) -> Self {
+		Self{ media_type, extensions: Vec::new() }
+	}
+}
+
+
+/// The [`Accept`](https://tools.ietf.org/html/rfc7231#section-5.3.2) header as list of weighted
+/// media ranges
+///
+/// An empty list (e.g. `Accept::default()` for a missing header or an empty field value) accepts
+/// every media type.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Accept(pub Vec<Weighted<MediaRange>>);
+impl Accept {
+	/// Gets the quality of `media_type` according to the most specific matching media range
+	///
+	/// This is `Quality::ONE` if the list is empty or `Quality::ZERO` if no media range matches.
+	pub fn quality(&self, media_type: &MediaType) -> Quality {
+		if self.0.is_empty() {
+			return Quality::ONE
+		}
+		
+		// Select the first of the most specific matching ranges
+		let mut selected: Option<&Weighted<MediaRange>> = None;
+		for range in self.0.iter().filter(|range| media_type.matches(&range.value.media_type)) {
+			let specificity = range.value.specificity();
+			if selected.map(|s| specificity > s.value.specificity()).unwrap_or(true) {
+				selected = Some(range);
+			}
+		}
+		selected.map(|range| range.quality).unwrap_or(Quality::ZERO)
+	}
+	/// Selects the best of the `available` media types (see `negotiate`)
+	pub fn negotiate(&self, available: &[MediaType]) -> Option<MediaType> {
+		select(available, |media_type| self.quality(media_type)).cloned()
+	}
+}
+impl TypedHeader for Accept {
+	const NAME: &'static str = "Accept";
+	/// Fails with `HttpError::ProtocolViolation` if a media range or a `q`-value is invalid.
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		let ranges = parse_list(values, |bytes| {
+			let (mut media_type, rest) = MediaType::parse_prefix(bytes)?;
+			if media_type.type_() == "*" && media_type.subtype() != "*" {
+				Err(HttpError::ProtocolViolation)?
+			}
+			
+			// Split the media type parameters from the weight and the accept-extensions
+			let (quality, extensions) = match media_type.split_off_quality() {
+				Some((quality, extensions)) => (Quality::parse(&quality)?, extensions),
+				None => (Quality::ONE, Vec::new())
+			};
+			Ok((Weighted::new(MediaRange{ media_type, extensions }, quality), rest))
+		})?;
+		Ok(Self(ranges))
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		let mut encoded = String::new();
+		for range in self.0.iter() {
+			if !encoded.is_empty() {
+				encoded.push_str(", ");
+			}
+			encoded.push_str(&range.value.media_type.to_string());
+			if range.quality != Quality::ONE || !range.value.extensions.is_empty() {
+				encoded.push_str(&format!(";q={}", range.quality));
+			}
+			for (name, value) in range.value.extensions.iter() {
+				encoded.push_str(&format!(";{}={}", name, quote(value)));
+			}
+		}
+		Data::try_from(encoded)
+			.expect("Should never fail because all media ranges are valid field values")
+	}
+}
+
+
+/// Selects the best of the `available` media types for `accept` according to
+/// [RFC 7231](https://tools.ietf.org/html/rfc7231#section-5.3.2)
+///
+/// Every available media type gets the quality of the most specific media range that matches it;
+/// the media type with the highest non-zero quality is selected and ties are resolved by the order
+/// of `available` (i.e. the server preference). An empty `accept` (e.g. for a missing header)
+/// selects the first available media type; `None` means that no media type is acceptable.
+pub fn negotiate(accept: &Accept, available: &[MediaType]) -> Option<MediaType> {
+	accept.negotiate(available)
+}
@@ -0,0 +1,59 @@
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ FieldValue, Token }
+	},
+	typed::{
+		Quality, TypedHeader, Weighted,
+		quality::{ encode_weighted_tokens, parse_list, parse_weighted_token, select, token_quality }
+	}
+};
+
+
+/// The [`Accept-Charset`](https://tools.ietf.org/html/rfc7231#section-5.3.3) header as list of
+/// weighted charsets (stored in lowercase)
+///
+/// An empty list (e.g. `AcceptCharset::default()` for a missing header) accepts every charset.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AcceptCharset(pub Vec<Weighted<Data<Token>>>);
+impl AcceptCharset {
+	/// Decodes the header from all raw field values (e.g. from `HeaderFields::get_all`) as one
+	/// comma-separated list; a missing field (i.e. no values) accepts every charset
+	///
+	/// Fails with `HttpError::ProtocolViolation` if a charset or a `q`-value is invalid.
+	pub fn from_fields<'a>(values: impl IntoIterator<Item = &'a Data<FieldValue>>)
+		-> Result<Self, HttpError>
+	{
+		let values: Vec<&Data<FieldValue>> = values.into_iter().collect();
+		match values.is_empty() {
+			false => Self::decode(&values),
+			true => Ok(Self::default())
+		}
+	}
+	
+	/// Gets the quality of `charset` (case-insensitive) according to its own entry or otherwise
+	/// the wildcard `*`
+	///
+	/// This is `Quality::ONE` if the list is empty or `Quality::ZERO` if no entry matches.
+	pub fn quality(&self, charset: &str) -> Quality {
+		match self.0.is_empty() {
+			true => Quality::ONE,
+			false => token_quality(&self.0, charset).unwrap_or(Quality::ZERO)
+		}
+	}
+	/// Selects the first of the `available` charsets with the highest non-zero quality
+	pub fn negotiate<'a>(&self, available: &[&'a str]) -> Option<&'a str> {
+		select(available, |charset| self.quality(charset)).copied()
+	}
+}
+impl TypedHeader for AcceptCharset {
+	const NAME: &'static str = "Accept-Charset";
+	/// Fails with `HttpError::ProtocolViolation` if a charset or a `q`-value is invalid.
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		Ok(Self(parse_list(values, parse_weighted_token)?))
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		encode_weighted_tokens(&self.0)
+	}
+}
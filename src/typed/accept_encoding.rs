@@ -0,0 +1,69 @@
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ FieldValue, Token }
+	},
+	typed::{
+		Quality, TypedHeader, Weighted,
+		quality::{ encode_weighted_tokens, parse_list, parse_weighted_token, select, token_quality }
+	}
+};
+use std::convert::TryFrom;
+
+
+/// The [`Accept-Encoding`](https://tools.ietf.org/html/rfc7231#section-5.3.4) header as list of
+/// weighted content codings (stored in lowercase)
+///
+/// An empty list (e.g. for an empty field value) only accepts `identity`; use `from_fields` to get
+/// a list that accepts every content coding if the header is missing.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AcceptEncoding(pub Vec<Weighted<Data<Token>>>);
+impl AcceptEncoding {
+	/// Decodes the header from all raw field values (e.g. from `HeaderFields::get_all`) as one
+	/// comma-separated list; a missing field (i.e. no values) accepts every content coding (i.e.
+	/// `*`)
+	///
+	/// Fails with `HttpError::ProtocolViolation` if a content coding or a `q`-value is invalid.
+	pub fn from_fields<'a>(values: impl IntoIterator<Item = &'a Data<FieldValue>>)
+		-> Result<Self, HttpError>
+	{
+		let values: Vec<&Data<FieldValue>> = values.into_iter().collect();
+		match values.is_empty() {
+			false => Self::decode(&values),
+			true => {
+				let any = Data::try_from("*").expect("Should never fail because `*` is a token");
+				Ok(Self(vec![Weighted::new(any, Quality::ONE)]))
+			}
+		}
+	}
+	
+	/// Gets the quality of the content coding `coding` (case-insensitive)
+	///
+	/// A coding gets the quality of its own entry or otherwise of the wildcard `*`; `identity` is
+	/// acceptable unless it is excluded by `identity;q=0` or by `*;q=0` without an own entry.
+	pub fn quality(&self, coding: &str) -> Quality {
+		match token_quality(&self.0, coding) {
+			Some(quality) => quality,
+			None if coding.eq_ignore_ascii_case("identity") => Quality::ONE,
+			None => Quality::ZERO
+		}
+	}
+	/// Selects the first of the `available` content codings with the highest non-zero quality
+	///
+	/// `None` means that no available coding is acceptable; add `identity` to `available` if the
+	/// unencoded representation is an option.
+	pub fn negotiate<'a>(&self, available: &[&'a str]) -> Option<&'a str> {
+		select(available, |coding| self.quality(coding)).copied()
+	}
+}
+impl TypedHeader for AcceptEncoding {
+	const NAME: &'static str = "Accept-Encoding";
+	/// Fails with `HttpError::ProtocolViolation` if a content coding or a `q`-value is invalid.
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		Ok(Self(parse_list(values, parse_weighted_token)?))
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		encode_weighted_tokens(&self.0)
+	}
+}
@@ -0,0 +1,148 @@
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ FieldValue, Token }
+	},
+	typed::{
+		Quality, TypedHeader, Weighted,
+		quality::{ encode_weighted_tokens, parse_list, parse_weighted_token, select }
+	}
+};
+use std::cmp::Reverse;
+
+
+/// Checks if `range` is a valid [language range](https://tools.ietf.org/html/rfc4647#section-2.1)
+/// (i.e. `*` or `1*8ALPHA *("-" 1*8alphanum)`)
+fn is_language_range(range: &[u8]) -> bool {
+	if range == b"*" {
+		return true
+	}
+	range.split(|b| *b == b'-').enumerate().all(|(index, subtag)| {
+		let is_valid = match index {
+			0 => u8::is_ascii_alphabetic,
+			_ => u8::is_ascii_alphanumeric
+		};
+		(1..=8).contains(&subtag.len()) && subtag.iter().all(is_valid)
+	})
+}
+/// Checks if the language tag `tag` is matched by the language range `range` according to
+/// [basic filtering](https://tools.ietf.org/html/rfc4647#section-3.3.1) (case-insensitive)
+fn basic_matches(range: &[u8], tag: &[u8]) -> bool {
+	match tag.get(..range.len()) {
+		_ if range == b"*" => true,
+		Some(prefix) => {
+			let is_boundary = matches!(tag.get(range.len()), None | Some(b'-'));
+			prefix.eq_ignore_ascii_case(range) && is_boundary
+		},
+		None => false
+	}
+}
+
+
+/// The [`Accept-Language`](https://tools.ietf.org/html/rfc7231#section-5.3.5) header as list of
+/// weighted language ranges (stored in lowercase)
+///
+/// An empty list (e.g. `AcceptLanguage::default()` for a missing header) accepts every language.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AcceptLanguage(pub Vec<Weighted<Data<Token>>>);
+impl AcceptLanguage {
+	/// Decodes the header from all raw field values (e.g. from `HeaderFields::get_all`) as one
+	/// comma-separated list; a missing field (i.e. no values) accepts every language
+	///
+	/// Fails with `HttpError::ProtocolViolation` if a language range or a `q`-value is invalid.
+	pub fn from_fields<'a>(values: impl IntoIterator<Item = &'a Data<FieldValue>>)
+		-> Result<Self, HttpError>
+	{
+		let values: Vec<&Data<FieldValue>> = values.into_iter().collect();
+		match values.is_empty() {
+			false => Self::decode(&values),
+			true => Ok(Self::default())
+		}
+	}
+	
+	/// Gets the quality of the language tag `tag` according to the most specific (i.e. longest)
+	/// language range that matches it by basic filtering
+	///
+	/// This is `Quality::ONE` if the list is empty or `Quality::ZERO` if no language range matches.
+	pub fn quality(&self, tag: &str) -> Quality {
+		if self.0.is_empty() {
+			return Quality::ONE
+		}
+		
+		// Select the first of the longest matching ranges (`*` is the least specific range)
+		let mut selected: Option<(&Weighted<Data<Token>>, usize)> = None;
+		for range in self.0.iter().filter(|range| basic_matches(&range.value, tag.as_bytes())) {
+			let specificity = if &range.value[..] == b"*" { 0 } else { range.value.len() };
+			if selected.map(|(_, s)| specificity > s).unwrap_or(true) {
+				selected = Some((range, specificity));
+			}
+		}
+		selected.map(|(range, _)| range.quality).unwrap_or(Quality::ZERO)
+	}
+	/// Selects the first of the `available` language tags with the highest non-zero quality
+	/// (see `quality`)
+	pub fn negotiate<'a>(&self, available: &[&'a str]) -> Option<&'a str> {
+		select(available, |tag| self.quality(tag)).copied()
+	}
+	/// Returns all `tags` with a non-zero quality ordered by their quality according to
+	/// [basic filtering](https://tools.ietf.org/html/rfc4647#section-3.3.1) (tags with the same
+	/// quality keep their order)
+	pub fn filter<'a>(&self, tags: &[&'a str]) -> Vec<&'a str> {
+		let mut filtered: Vec<(&'a str, Quality)> = tags.iter()
+			.map(|tag| (*tag, self.quality(tag)))
+			.filter(|(_, quality)| *quality > Quality::ZERO)
+			.collect();
+		filtered.sort_by_key(|(_, quality)| Reverse(*quality));
+		filtered.into_iter().map(|(tag, _)| tag).collect()
+	}
+	/// Selects the best of the `tags` according to
+	/// [lookup](https://tools.ietf.org/html/rfc4647#section-3.4)
+	///
+	/// The language ranges are tried in the order of their quality (ignoring `*` and ranges with
+	/// the quality `0`); each range is progressively truncated (e.g. `de-ch-1996`, `de-ch`, `de`)
+	/// until it is equal to a tag (case-insensitive). `None` means that the caller should use its
+	/// default language.
+	pub fn lookup<'a>(&self, tags: &[&'a str]) -> Option<&'a str> {
+		let mut ranges: Vec<&Weighted<Data<Token>>> = self.0.iter()
+			.filter(|range| range.quality > Quality::ZERO && &range.value[..] != b"*")
+			.collect();
+		ranges.sort_by_key(|range| Reverse(range.quality));
+		
+		for range in ranges {
+			let mut range: &[u8] = &range.value;
+			loop {
+				let is_equal = |tag: &&&str| tag.as_bytes().eq_ignore_ascii_case(range);
+				if let Some(tag) = tags.iter().find(is_equal) {
+					return Some(tag)
+				}
+				
+				// Remove the last subtag and a preceding single-character subtag (e.g. `x` or `u`)
+				range = match range.iter().rposition(|b| *b == b'-') {
+					Some(index) => &range[..index],
+					None => break
+				};
+				if let Some(index) = range.iter().rposition(|b| *b == b'-') {
+					if range.len() - index == 2 {
+						range = &range[..index];
+					}
+				}
+			}
+		}
+		None
+	}
+}
+impl TypedHeader for AcceptLanguage {
+	const NAME: &'static str = "Accept-Language";
+	/// Fails with `HttpError::ProtocolViolation` if a language range or a `q`-value is invalid.
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		let ranges = parse_list(values, |bytes| match parse_weighted_token(bytes)? {
+			(range, rest) if is_language_range(&range.value) => Ok((range, rest)),
+			_ => Err(HttpError::ProtocolViolation)
+		})?;
+		Ok(Self(ranges))
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		encode_weighted_tokens(&self.0)
+	}
+}
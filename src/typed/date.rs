@@ -0,0 +1,191 @@
+use crate::HttpError;
+use std::{
+	str,
+	convert::TryFrom,
+	fmt::{ self, Display, Formatter },
+	time::{ Duration, SystemTime, UNIX_EPOCH }
+};
+
+
+/// The short day names starting with Thursday (the weekday of `1970-01-01`)
+const DAY_NAMES: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+/// The long day names starting with Thursday (the weekday of `1970-01-01`)
+const LONG_DAY_NAMES: [&str; 7] = [
+	"Thursday", "Friday", "Saturday", "Sunday", "Monday", "Tuesday", "Wednesday"
+];
+/// The short month names
+const MONTH_NAMES: [&str; 12] = [
+	"Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"
+];
+/// The seconds per day
+const SECS_PER_DAY: u64 = 86_400;
+
+
+/// Computes the days since `1970-01-01` for a date in the proleptic Gregorian calendar (see
+/// [`days_from_civil`](http://howardhinnant.github.io/date_algorithms.html#days_from_civil))
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let (era, year_of_era) = (year / 400, year % 400);
+	let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	era * 146_097 + day_of_era - 719_468
+}
+/// Computes the year, month and day for the days since `1970-01-01` (the inverse of
+/// `days_from_civil`)
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+	let days = days + 719_468;
+	let (era, day_of_era) = (days / 146_097, days % 146_097);
+	let year_of_era =
+		(day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month_index = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+	let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+	let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+	(year, month, day)
+}
+/// Gets the amount of days in `month` of `year`
+fn days_in_month(year: u64, month: u64) -> u64 {
+	let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+	match month {
+		2 if is_leap_year => 29,
+		2 => 28,
+		4 | 6 | 9 | 11 => 30,
+		_ => 31
+	}
+}
+
+
+/// A timestamp with second precision as used by the `Date` header and others (see
+/// [RFC 7231](https://tools.ietf.org/html/rfc7231#section-7.1.1.1))
+///
+/// The timestamp is always serialized as IMF-fixdate (e.g. `Sun, 06 Nov 1994 08:49:37 GMT`) but
+/// can be parsed from the obsolete RFC 850 and asctime formats, too.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct HttpDate {
+	/// The seconds since `1970-01-01T00:00:00Z`
+	secs: u64
+}
+impl HttpDate {
+	/// Creates a new timestamp from the seconds since `1970-01-01T00:00:00Z`
+	pub fn from_unix_secs(secs: u64) -> Self {
+		Self{ secs }
+	}
+	/// The seconds since `1970-01-01T00:00:00Z`
+	pub fn unix_secs(&self) -> u64 {
+		self.secs
+	}
+	
+	/// Parses a date in one of the formats allowed by
+	/// [RFC 7231](https://tools.ietf.org/html/rfc7231#section-7.1.1.1)
+	///
+	/// Fails with `HttpError::InvalidEncoding` if `bytes` are not ASCII or with
+	/// `HttpError::ProtocolViolation` if `bytes` are not a valid date. Two-digit years of the
+	/// RFC 850 format are interpreted as `1970` to `2069`.
+	pub fn parse(bytes: &[u8]) -> Result<Self, HttpError> {
+		/// Parses `digits` as decimal integer with `len` digits (if `len` is `Some`)
+		fn number(digits: &str, len: Option<usize>) -> Result<u64, HttpError> {
+			let len_ok = len.map(|len| digits.len() == len).unwrap_or(!digits.is_empty());
+			match len_ok && digits.len() <= 4 && digits.bytes().all(|b| b.is_ascii_digit()) {
+				true => Ok(digits.parse().expect("Should never fail because `digits` are valid")),
+				false => Err(HttpError::ProtocolViolation)
+			}
+		}
+		/// Gets the month number for `name`
+		fn month(name: &str) -> Result<u64, HttpError> {
+			let month = MONTH_NAMES.iter().position(|m| *m == name);
+			Ok(month.ok_or(HttpError::ProtocolViolation)? as u64 + 1)
+		}
+		/// Parses a `HH:MM:SS` time of day into seconds
+		fn time(time: &str) -> Result<u64, HttpError> {
+			let parts: Vec<&str> = time.split(':').collect();
+			let (hour, minute, second) = match parts.as_slice() {
+				[hour, minute, second] => {
+					(number(hour, Some(2))?, number(minute, Some(2))?, number(second, Some(2))?)
+				},
+				_ => Err(HttpError::ProtocolViolation)?
+			};
+			match hour < 24 && minute < 60 && second < 61 {
+				true => Ok(hour * 3600 + minute * 60 + second.min(59)),
+				false => Err(HttpError::ProtocolViolation)
+			}
+		}
+		
+		// Split the date into its parts and select the format
+		let date = str::from_utf8(bytes).ok().filter(|date| date.is_ascii())
+			.ok_or(HttpError::InvalidEncoding)?;
+		let parts: Vec<&str> = date.split(' ').filter(|part| !part.is_empty()).collect();
+		let (year, month, day, time, day_name) = match parts.as_slice() {
+			// IMF-fixdate: `Sun, 06 Nov 1994 08:49:37 GMT`
+			[day_name, day, month_name, year, time_of_day, "GMT"] => {
+				let day_name = day_name.strip_suffix(',').ok_or(HttpError::ProtocolViolation)?;
+				let year = number(year, Some(4))?;
+				(year, month(month_name)?, number(day, Some(2))?, time(time_of_day)?, day_name)
+			},
+			// RFC 850: `Sunday, 06-Nov-94 08:49:37 GMT`
+			[day_name, date, time_of_day, "GMT"] => {
+				let day_name = day_name.strip_suffix(',').ok_or(HttpError::ProtocolViolation)?;
+				let date: Vec<&str> = date.split('-').collect();
+				let (day, month_name, year) = match date.as_slice() {
+					[day, month_name, year] => (*day, *month_name, number(year, Some(2))?),
+					_ => Err(HttpError::ProtocolViolation)?
+				};
+				let year = if year < 70 { 2000 + year } else { 1900 + year };
+				(year, month(month_name)?, number(day, Some(2))?, time(time_of_day)?, day_name)
+			},
+			// asctime: `Sun Nov  6 08:49:37 1994`
+			[day_name, month_name, day, time_of_day, year] => {
+				let year = number(year, Some(4))?;
+				(year, month(month_name)?, number(day, None)?, time(time_of_day)?, *day_name)
+			},
+			_ => Err(HttpError::ProtocolViolation)?
+		};
+		
+		// Validate the date and the day name
+		if year < 1970 || day == 0 || day > days_in_month(year, month) {
+			Err(HttpError::ProtocolViolation)?
+		}
+		let days = days_from_civil(year, month, day);
+		let weekday = (days % 7) as usize;
+		if day_name != DAY_NAMES[weekday] && day_name != LONG_DAY_NAMES[weekday] {
+			Err(HttpError::ProtocolViolation)?
+		}
+		Ok(Self{ secs: days * SECS_PER_DAY + time })
+	}
+}
+impl Display for HttpDate {
+	/// Formats the timestamp as IMF-fixdate (e.g. `Sun, 06 Nov 1994 08:49:37 GMT`)
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let (days, secs) = (self.secs / SECS_PER_DAY, self.secs % SECS_PER_DAY);
+		let (year, month, day) = civil_from_days(days);
+		write!(
+			f, "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+			DAY_NAMES[(days % 7) as usize], day, MONTH_NAMES[month as usize - 1], year,
+			secs / 3600, secs % 3600 / 60, secs % 60
+		)
+	}
+}
+impl From<SystemTime> for HttpDate {
+	/// Converts `time` and truncates it to whole seconds (times before `1970-01-01` are clamped)
+	fn from(time: SystemTime) -> Self {
+		let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+		Self{ secs }
+	}
+}
+impl From<HttpDate> for SystemTime {
+	fn from(date: HttpDate) -> Self {
+		UNIX_EPOCH + Duration::from_secs(date.secs)
+	}
+}
+impl TryFrom<&[u8]> for HttpDate {
+	type Error = HttpError;
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		Self::parse(bytes)
+	}
+}
+impl TryFrom<&str> for HttpDate {
+	type Error = HttpError;
+	fn try_from(string: &str) -> Result<Self, Self::Error> {
+		Self::parse(string.as_bytes())
+	}
+}
@@ -0,0 +1,190 @@
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ FieldValue, Token, Uri }
+	},
+	header::hardening::content_length_value,
+	typed::{ HttpDate, MediaType, TypedHeader, join, single, tokens },
+	uri::ParsedUri
+};
+use std::convert::TryFrom;
+
+
+/// Implements a `TypedHeader` with a single, unstructured field value
+macro_rules! raw_value_header {
+	($(#[$doc:meta])* $struct:ident => $name:expr) => {
+		$(#[$doc])*
+		#[derive(Debug, Clone, Eq, PartialEq)]
+		pub struct $struct(pub Data<FieldValue>);
+		impl TypedHeader for $struct {
+			const NAME: &'static str = $name;
+			fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+				Ok(Self(single(values)?.clone()))
+			}
+			fn encode(&self) -> Data<FieldValue> {
+				self.0.clone()
+			}
+		}
+	};
+}
+
+
+/// The [`Content-Length`](https://tools.ietf.org/html/rfc7230#section-3.3.2) header
+///
+/// Multiple identical values (or a list of identical values) are accepted; anything else fails
+/// with `HttpError::InvalidContentLength` or `HttpError::ConflictingContentLength`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ContentLength(pub u64);
+impl TypedHeader for ContentLength {
+	const NAME: &'static str = "Content-Length";
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		Ok(Self(content_length_value(values.iter().copied())?))
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		Data::try_from(self.0.to_string())
+			.expect("Should never fail because all number literals are valid field values")
+	}
+}
+
+
+/// The [`Host`](https://tools.ietf.org/html/rfc7230#section-5.4) header
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Host {
+	/// The host (a registered name or an IP address)
+	pub host: Data<Uri>,
+	/// The port if any
+	pub port: Option<u16>
+}
+impl TypedHeader for Host {
+	const NAME: &'static str = "Host";
+	/// Fails with `HttpError::ProtocolViolation` if there are multiple values or if the value is
+	/// not a valid `host[:port]`.
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		// Parse the value as authority of a network-path reference
+		let authority = [b"//".as_ref(), single(values)?].concat();
+		let uri = ParsedUri::parse(&authority)?;
+		let is_authority = uri.userinfo().is_none() && uri.path().is_empty()
+			&& uri.query().is_none() && uri.fragment().is_none();
+		match (uri.host(), is_authority) {
+			(Some(host), true) => Ok(Self{ host: host.clone(), port: uri.port() }),
+			_ => Err(HttpError::ProtocolViolation)
+		}
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		let mut value = self.host.to_vec();
+		if let Some(port) = self.port {
+			value.extend_from_slice(format!(":{}", port).as_bytes());
+		}
+		Data::try_from(value).expect("Should never fail because a host is a valid field value")
+	}
+}
+
+
+/// The [`Connection`](https://tools.ietf.org/html/rfc7230#section-6.1) header as list of
+/// connection options
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Connection(pub Vec<Data<Token>>);
+impl Connection {
+	/// Checks if `option` is set (case-insensitive)
+	pub fn contains(&self, option: &str) -> bool {
+		self.0.iter().any(|o| o.eq_ignore_ascii_case(option.as_bytes()))
+	}
+	/// Checks if the `close` option is set
+	pub fn is_close(&self) -> bool {
+		self.contains("close")
+	}
+	/// Checks if the `keep-alive` option is set
+	pub fn is_keep_alive(&self) -> bool {
+		self.contains("keep-alive")
+	}
+}
+impl TypedHeader for Connection {
+	const NAME: &'static str = "Connection";
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		Ok(Self(tokens(values)?))
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		join(&self.0)
+	}
+}
+
+
+/// The [`Transfer-Encoding`](https://tools.ietf.org/html/rfc7230#section-3.3.1) header as list
+/// of transfer codings in the order they were applied
+///
+/// _Note: transfer parameters are not supported and fail with `HttpError::InvalidEncoding`._
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TransferEncoding(pub Vec<Data<Token>>);
+impl TransferEncoding {
+	/// Checks if `chunked` is the final transfer coding
+	pub fn is_chunked(&self) -> bool {
+		self.0.last().map(|coding| coding.eq_ignore_ascii_case(b"chunked")).unwrap_or(false)
+	}
+}
+impl TypedHeader for TransferEncoding {
+	const NAME: &'static str = "Transfer-Encoding";
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		Ok(Self(tokens(values)?))
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		join(&self.0)
+	}
+}
+
+
+/// The [`Content-Type`](https://tools.ietf.org/html/rfc7231#section-3.1.1.5) header
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ContentType(pub MediaType);
+impl TypedHeader for ContentType {
+	const NAME: &'static str = "Content-Type";
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		Ok(Self(MediaType::parse(single(values)?)?))
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		Data::try_from(self.0.to_string())
+			.expect("Should never fail because a formatted media type is a valid field value")
+	}
+}
+
+
+/// The [`Date`](https://tools.ietf.org/html/rfc7231#section-7.1.1.2) header
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Date(pub HttpDate);
+impl TypedHeader for Date {
+	const NAME: &'static str = "Date";
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		Ok(Self(HttpDate::parse(single(values)?)?))
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		Data::try_from(self.0.to_string())
+			.expect("Should never fail because a formatted date is a valid field value")
+	}
+}
+
+
+/// The [`Location`](https://tools.ietf.org/html/rfc7231#section-7.1.2) header as URI reference
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Location(pub Data<Uri>);
+impl TypedHeader for Location {
+	const NAME: &'static str = "Location";
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError> {
+		let value = single(values)?;
+		ParsedUri::parse(value)?;
+		Ok(Self(Data::try_from(value as &[u8])?))
+	}
+	fn encode(&self) -> Data<FieldValue> {
+		Data::try_from(self.0.to_vec())
+			.expect("Should never fail because a URI is a valid field value")
+	}
+}
+
+
+raw_value_header! {
+	/// The [`Server`](https://tools.ietf.org/html/rfc7231#section-7.4.2) header
+	Server => "Server"
+}
+raw_value_header! {
+	/// The [`User-Agent`](https://tools.ietf.org/html/rfc7231#section-5.5.3) header
+	UserAgent => "User-Agent"
+}
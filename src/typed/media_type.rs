@@ -0,0 +1,195 @@
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ FieldValue, Token }
+	},
+	typed::{ quote, skip_ows, split_token, split_value }
+};
+use std::{
+	convert::TryFrom,
+	fmt::{ self, Display, Formatter }
+};
+
+
+/// A list of `(name, value)`-parameters
+type Params = Vec<(Data<Token>, Data<FieldValue>)>;
+
+
+/// A [media type](https://tools.ietf.org/html/rfc7231#section-3.1.1.1) (e.g.
+/// `application/json; charset=utf-8`)
+///
+/// The type, the subtype and the parameter names are case-insensitive and stored in lowercase;
+/// the parameter values are stored unescaped and keep their case. The type and the subtype may be
+/// the wildcard `*` so that a `MediaType` can be used as media range, too.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MediaType {
+	type_: Data<Token>,
+	subtype: Data<Token>,
+	params: Params
+}
+impl MediaType {
+	/// Creates a new media type without parameters
+	///
+	/// Fails with `HttpError::InvalidEncoding` if `type_` or `subtype` are not tokens.
+	pub fn new(type_: &str, subtype: &str) -> Result<Self, HttpError> {
+		let type_ = Data::try_from(type_.to_ascii_lowercase())?;
+		let subtype = Data::try_from(subtype.to_ascii_lowercase())?;
+		Ok(Self{ type_, subtype, params: Vec::new() })
+	}
+	/// Sets the parameter `name` to `value` and replaces an existing value for `name`
+	///
+	/// Fails with `HttpError::InvalidEncoding` if `name` is not a token or if `value` is not a
+	/// valid ASCII field value.
+	pub fn with_param(mut self, name: &str, value: &str) -> Result<Self, HttpError> {
+		let name: Data<Token> = Data::try_from(name.to_ascii_lowercase())?;
+		if !value.is_ascii() {
+			Err(HttpError::InvalidEncoding)?
+		}
+		let value = Data::try_from(value)?;
+		match self.params.iter_mut().find(|(n, _)| *n == name) {
+			Some(param) => param.1 = value,
+			None => self.params.push((name, value))
+		}
+		Ok(self)
+	}
+	
+	/// Parses a media type
+	///
+	/// Fails with `HttpError::ProtocolViolation` if `bytes` are not a valid media type or with
+	/// `HttpError::InvalidEncoding` if a quoted parameter value contains invalid characters.
+	pub fn parse(bytes: &[u8]) -> Result<Self, HttpError> {
+		match Self::parse_prefix(bytes)? {
+			(media_type, rest) if skip_ows(rest).is_empty() => Ok(media_type),
+			_ => Err(HttpError::ProtocolViolation)
+		}
+	}
+	/// Parses a media type from the beginning of `bytes` until the end or the next top-level `,`
+	/// and returns the remaining bytes
+	pub(in crate::typed) fn parse_prefix(bytes: &[u8]) -> Result<(Self, &[u8]), HttpError> {
+		// Parse the type and the subtype
+		let (type_, rest) = split_token(skip_ows(bytes))?;
+		let rest = match rest {
+			[b'/', rest @ ..] => rest,
+			_ => Err(HttpError::ProtocolViolation)?
+		};
+		let (subtype, mut rest) = split_token(rest)?;
+		
+		// Parse the parameters (empty parameters like in `text/plain;` are tolerated)
+		let mut params = Vec::new();
+		loop {
+			rest = match skip_ows(rest) {
+				[b';', rest @ ..] => skip_ows(rest),
+				rest => return Ok((Self{ type_, subtype, params }, rest))
+			};
+			if rest.is_empty() || rest[0] == b';' || rest[0] == b',' {
+				continue;
+			}
+			
+			let (name, tail) = split_token(rest)?;
+			let tail = match tail {
+				[b'=', tail @ ..] => tail,
+				_ => Err(HttpError::ProtocolViolation)?
+			};
+			let (value, tail) = split_value(tail)?;
+			params.push((name, value));
+			rest = tail;
+		}
+	}
+	
+	/// The type (e.g. `application`)
+	pub fn type_(&self) -> &str {
+		self.type_.as_ref()
+	}
+	/// The subtype including the suffix (e.g. `vnd.api+json`)
+	pub fn subtype(&self) -> &str {
+		self.subtype.as_ref()
+	}
+	/// The [structured syntax suffix](https://tools.ietf.org/html/rfc6838#section-4.2.8) of the
+	/// subtype if any (e.g. `json` for `application/vnd.api+json`)
+	pub fn suffix(&self) -> Option<&str> {
+		let subtype = self.subtype();
+		subtype.rfind('+').map(|index| &subtype[index + 1..]).filter(|suffix| !suffix.is_empty())
+	}
+	/// The type and the subtype without parameters (e.g. `application/json`)
+	pub fn essence(&self) -> String {
+		format!("{}/{}", self.type_(), self.subtype())
+	}
+	/// Gets the value of the parameter `name` (case-insensitive) if any
+	pub fn param(&self, name: &str) -> Option<&Data<FieldValue>> {
+		self.params.iter().find(|(n, _)| n.eq_ignore_ascii_case(name.as_bytes())).map(|(_, v)| v)
+	}
+	/// The parameters as `(name, value)`-pairs in their order
+	pub fn params(&self) -> &[(Data<Token>, Data<FieldValue>)] {
+		&self.params
+	}
+	
+	/// Splits off the first `q`-parameter and all subsequent parameters and returns the `q`-value
+	/// and the subsequent parameters (i.e. the accept-extensions of a media range)
+	pub(in crate::typed) fn split_off_quality(&mut self) -> Option<(Data<FieldValue>, Params)> {
+		let index = self.params.iter().position(|(name, _)| &name[..] == b"q")?;
+		let mut extensions = self.params.split_off(index);
+		let (_, quality) = extensions.remove(0);
+		Some((quality, extensions))
+	}
+	
+	/// Checks if the type or the subtype is the wildcard `*`
+	pub fn is_wildcard(&self) -> bool {
+		&self.type_[..] == b"*" || &self.subtype[..] == b"*"
+	}
+	/// Checks if the media type matches `pattern` (case-insensitive)
+	///
+	/// A `pattern` with `/` is compared against the type and the subtype and may use the wildcard
+	/// `*` (e.g. `text/*`); a `pattern` without `/` is compared against the subtype and the suffix
+	/// (e.g. `json` matches `application/json` and `application/vnd.api+json`).
+	pub fn is(&self, pattern: &str) -> bool {
+		let matches = |pattern: &str, value: &str| {
+			pattern == "*" || pattern.eq_ignore_ascii_case(value)
+		};
+		match pattern.find('/') {
+			Some(index) => {
+				let (type_, subtype) = (&pattern[..index], &pattern[index + 1..]);
+				matches(type_, self.type_()) && matches(subtype, self.subtype())
+			},
+			None => {
+				let is_suffix = |suffix: &str| pattern.eq_ignore_ascii_case(suffix);
+				pattern.eq_ignore_ascii_case(self.subtype()) || self.suffix().is_some_and(is_suffix)
+			}
+		}
+	}
+	/// Checks if the media type is matched by the media range `range` (e.g. `text/html;
+	/// charset=utf-8` is matched by `text/*` and `text/html;charset=UTF-8` but not by
+	/// `text/html;level=1`)
+	///
+	/// All parameters of `range` must be present with an equal value; `charset` values are
+	/// compared case-insensitive.
+	pub fn matches(&self, range: &MediaType) -> bool {
+		let type_matches = &range.type_[..] == b"*" || range.type_ == self.type_;
+		let subtype_matches = &range.subtype[..] == b"*" || range.subtype == self.subtype;
+		let params_match = range.params.iter().all(|(name, value)| match self.param(name.as_ref()) {
+			Some(own) if &name[..] == b"charset" => own.eq_ignore_ascii_case(value),
+			Some(own) => own == value,
+			None => false
+		});
+		type_matches && subtype_matches && params_match
+	}
+}
+impl Display for MediaType {
+	/// Formats the media type and quotes the parameter values if necessary
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{}/{}", self.type_, self.subtype)?;
+		self.params.iter().try_for_each(|(name, value)| write!(f, ";{}={}", name, quote(value)))
+	}
+}
+impl TryFrom<&[u8]> for MediaType {
+	type Error = HttpError;
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		Self::parse(bytes)
+	}
+}
+impl TryFrom<&str> for MediaType {
+	type Error = HttpError;
+	fn try_from(string: &str) -> Result<Self, Self::Error> {
+		Self::parse(string.as_bytes())
+	}
+}
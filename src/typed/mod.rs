@@ -0,0 +1,148 @@
+//! Typed header fields
+//!
+//! A `TypedHeader` knows its field name and how to decode itself from the raw field values and
+//! encode itself into a field value; see `RequestHeader::typed_get` and
+//! `RequestBuilder::typed_insert`.
+
+mod accept;
+mod accept_charset;
+mod accept_encoding;
+mod accept_language;
+mod date;
+mod headers;
+mod media_type;
+mod quality;
+
+pub use self::{
+	accept::{ Accept, MediaRange, negotiate },
+	accept_charset::AcceptCharset,
+	accept_encoding::AcceptEncoding,
+	accept_language::AcceptLanguage,
+	date::HttpDate,
+	media_type::MediaType,
+	quality::{ Quality, Weighted },
+	headers::{
+		Connection, ContentLength, ContentType, Date, Host, Location, Server, TransferEncoding,
+		UserAgent
+	}
+};
+
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ Encoding, FieldValue, Token }
+	},
+	helpers::slice_ext::{ ByteSliceExt, SliceExt }
+};
+use std::convert::TryFrom;
+
+
+/// A header field with a typed value
+pub trait TypedHeader: Sized {
+	/// The field name
+	const NAME: &'static str;
+	
+	/// Decodes the typed value from all raw `values` for `Self::NAME` in their order (there is
+	/// always at least one value)
+	fn decode(values: &[&Data<FieldValue>]) -> Result<Self, HttpError>;
+	/// Encodes the typed value into a single raw field value
+	fn encode(&self) -> Data<FieldValue>;
+}
+
+
+/// Gets the single value of a field that must not occur multiple times
+///
+/// Fails with `HttpError::ProtocolViolation` if there is more than one value.
+fn single<'a>(values: &[&'a Data<FieldValue>]) -> Result<&'a Data<FieldValue>, HttpError> {
+	match values {
+		[value] => Ok(value),
+		_ => Err(HttpError::ProtocolViolation)
+	}
+}
+/// Splits all `values` of a comma-separated list field into tokens and ignores empty list
+/// elements (see [RFC 7230](https://tools.ietf.org/html/rfc7230#section-7))
+///
+/// Fails with `HttpError::InvalidEncoding` if a list element is not a token.
+fn tokens(values: &[&Data<FieldValue>]) -> Result<Vec<Data<Token>>, HttpError> {
+	let mut tokens = Vec::new();
+	for value in values {
+		for element in value.split_pat(b",").map(|element| element.trim()) {
+			if !element.is_empty() {
+				tokens.push(Data::try_from(element)?);
+			}
+		}
+	}
+	Ok(tokens)
+}
+/// Joins `tokens` into a comma-separated list
+fn join(tokens: &[Data<Token>]) -> Data<FieldValue> {
+	let mut joined = Vec::new();
+	for token in tokens {
+		if !joined.is_empty() {
+			joined.extend_from_slice(b", ");
+		}
+		joined.extend_from_slice(token);
+	}
+	Data::try_from(joined).expect("Should never fail because a token list is a valid field value")
+}
+/// Skips the optional whitespace at the beginning of `bytes`
+fn skip_ows(bytes: &[u8]) -> &[u8] {
+	let len = bytes.iter().take_while(|b| **b == b' ' || **b == b'\t').count();
+	&bytes[len..]
+}
+/// Splits a token from the beginning of `bytes`
+///
+/// Fails with `HttpError::ProtocolViolation` if `bytes` do not start with a token.
+fn split_token(bytes: &[u8]) -> Result<(Data<Token>, &[u8]), HttpError> {
+	let len = Token::first_invalid(bytes).unwrap_or(bytes.len());
+	let token = Data::try_from(bytes[..len].to_ascii_lowercase())
+		.map_err(|_| HttpError::ProtocolViolation)?;
+	Ok((token, &bytes[len..]))
+}
+/// Splits a token or a [quoted-string](https://tools.ietf.org/html/rfc7230#section-3.2.6) from
+/// the beginning of `bytes` and unescapes it
+///
+/// Fails with `HttpError::ProtocolViolation` if `bytes` do not start with a token or a terminated
+/// quoted-string or with `HttpError::InvalidEncoding` if the quoted-string contains invalid
+/// characters (including the obsolete `obs-text`).
+fn split_value(bytes: &[u8]) -> Result<(Data<FieldValue>, &[u8]), HttpError> {
+	if bytes.first() != Some(&b'"') {
+		let (value, rest) = split_token(bytes)?;
+		let value = Data::try_from(&bytes[..value.len()])
+			.expect("Should never fail because a token is a valid field value");
+		return Ok((value, rest))
+	}
+	
+	// Unescape the quoted-string
+	let (mut pos, mut value) = (1, Vec::new());
+	loop {
+		match &bytes[pos..] {
+			[b'"', ..] => break,
+			[b'\\', escaped, ..] => {
+				value.push(*escaped);
+				pos += 2;
+			},
+			[b, ..] => {
+				value.push(*b);
+				pos += 1;
+			},
+			[] => Err(HttpError::ProtocolViolation)?
+		}
+	}
+	if !value.is_ascii() {
+		Err(HttpError::InvalidEncoding)?
+	}
+	Ok((Data::try_from(value)?, &bytes[pos + 1..]))
+}
+/// Formats `value` as token or, if it is not a token, as
+/// [quoted-string](https://tools.ietf.org/html/rfc7230#section-3.2.6)
+fn quote(value: &Data<FieldValue>) -> String {
+	match Token::is_valid(value) {
+		true => value.to_string(),
+		false => {
+			let escaped = value.to_string().replace('\\', "\\\\").replace('"', "\\\"");
+			format!("\"{}\"", escaped)
+		}
+	}
+}
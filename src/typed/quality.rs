@@ -0,0 +1,186 @@
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ FieldValue, Token }
+	},
+	typed::{ skip_ows, split_token, split_value }
+};
+use std::{
+	convert::TryFrom,
+	fmt::{ self, Display, Formatter }
+};
+
+
+/// A [quality value](https://tools.ietf.org/html/rfc7231#section-5.3.1) (`q`-parameter) with a
+/// precision of three decimal places
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Quality(u16);
+impl Quality {
+	/// The quality `0` (i.e. "not acceptable")
+	pub const ZERO: Self = Self(0);
+	/// The quality `1` (the default if the `q`-parameter is omitted)
+	pub const ONE: Self = Self(1000);
+	
+	/// Creates a new quality value from thousandths (e.g. `500` for `0.5`)
+	///
+	/// Fails with `HttpError::ApiMisuse` if `thousandths` is greater than `1000`.
+	pub fn from_thousandths(thousandths: u16) -> Result<Self, HttpError> {
+		match thousandths {
+			0..=1000 => Ok(Self(thousandths)),
+			_ => Err(HttpError::ApiMisuse)
+		}
+	}
+	/// The quality value in thousandths (e.g. `500` for `0.5`)
+	pub fn as_thousandths(self) -> u16 {
+		self.0
+	}
+	
+	/// Parses a quality value (e.g. `0.5`)
+	///
+	/// Fails with `HttpError::ProtocolViolation` if `bytes` are not a valid quality value.
+	pub fn parse(bytes: &[u8]) -> Result<Self, HttpError> {
+		let (integer, fraction) = match bytes {
+			[integer] => (*integer, b"".as_ref()),
+			[integer, b'.', fraction @ ..] if fraction.len() <= 3 => (*integer, fraction),
+			_ => Err(HttpError::ProtocolViolation)?
+		};
+		if !fraction.iter().all(u8::is_ascii_digit) {
+			Err(HttpError::ProtocolViolation)?
+		}
+		
+		// Compute the thousandths
+		let fraction = fraction.iter().chain(b"000").take(3)
+			.fold(0, |thousandths, digit| thousandths * 10 + (digit - b'0') as u16);
+		match (integer, fraction) {
+			(b'0', fraction) => Ok(Self(fraction)),
+			(b'1', 0) => Ok(Self::ONE),
+			_ => Err(HttpError::ProtocolViolation)
+		}
+	}
+}
+impl Default for Quality {
+	fn default() -> Self {
+		Self::ONE
+	}
+}
+impl Display for Quality {
+	/// Formats the quality value without trailing zeroes (e.g. `1`, `0.5` or `0.005`)
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self.0 {
+			1000 => f.write_str("1"),
+			0 => f.write_str("0"),
+			thousandths => {
+				let fraction = format!("{:03}", thousandths);
+				write!(f, "0.{}", fraction.trim_end_matches('0'))
+			}
+		}
+	}
+}
+
+
+/// An element of a weighted list (e.g. `gzip;q=0.5` in an `Accept-Encoding` header)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Weighted<T> {
+	/// The value
+	pub value: T,
+	/// The quality of the value
+	pub quality: Quality
+}
+impl<T> Weighted<T> {
+	/// Creates a new weighted list element
+	pub fn new(value: T, quality: Quality) -> Self {
+		Self{ value, quality }
+	}
+}
+
+
+/// Parses the comma-separated list elements of all `values` using `parse` and ignores empty list
+/// elements (see [RFC 7230](https://tools.ietf.org/html/rfc7230#section-7))
+///
+/// `parse` gets the bytes starting at an element and returns the element and the remaining bytes.
+/// Fails with `HttpError::ProtocolViolation` if an element is not followed by `,` or the end.
+pub(in crate::typed) fn parse_list<T>(values: &[&Data<FieldValue>],
+	parse: impl Fn(&[u8]) -> Result<(T, &[u8]), HttpError>) -> Result<Vec<T>, HttpError>
+{
+	let mut elements = Vec::new();
+	for value in values {
+		let mut rest = skip_ows(value);
+		while !rest.is_empty() {
+			if rest[0] == b',' {
+				rest = skip_ows(&rest[1..]);
+				continue;
+			}
+			
+			let (element, tail) = parse(rest)?;
+			rest = match skip_ows(tail) {
+				tail @ [] | tail @ [b',', ..] => tail,
+				_ => Err(HttpError::ProtocolViolation)?
+			};
+			elements.push(element);
+		}
+	}
+	Ok(elements)
+}
+/// Selects the first of the `available` values with the highest quality according to
+/// `quality_of` (values with the quality `0` are never selected)
+pub(in crate::typed) fn select<T>(available: &[T], quality_of: impl Fn(&T) -> Quality)
+	-> Option<&T>
+{
+	let mut selected: Option<(&T, Quality)> = None;
+	for value in available {
+		let quality = quality_of(value);
+		let is_better = selected.map(|(_, selected)| quality > selected).unwrap_or(true);
+		if quality > Quality::ZERO && is_better {
+			selected = Some((value, quality));
+		}
+	}
+	selected.map(|(value, _)| value)
+}
+/// Parses a token with an optional weight (e.g. `gzip;q=0.5`) from the beginning of `bytes` and
+/// returns the remaining bytes
+///
+/// Fails with `HttpError::ProtocolViolation` if the element is invalid or has other parameters
+/// than `q`.
+pub(in crate::typed) fn parse_weighted_token(bytes: &[u8])
+	-> Result<(Weighted<Data<Token>>, &[u8]), HttpError>
+{
+	let (token, rest) = split_token(bytes)?;
+	match skip_ows(rest) {
+		[b';', rest @ ..] => {
+			let rest = match split_token(skip_ows(rest))? {
+				(name, [b'=', rest @ ..]) if &name[..] == b"q" => rest,
+				_ => Err(HttpError::ProtocolViolation)?
+			};
+			let (quality, rest) = split_value(rest)?;
+			Ok((Weighted::new(token, Quality::parse(&quality)?), rest))
+		},
+		_ => Ok((Weighted::new(token, Quality::ONE), rest))
+	}
+}
+/// Encodes weighted `tokens` into a comma-separated list (the weight `1` is omitted)
+pub(in crate::typed) fn encode_weighted_tokens(tokens: &[Weighted<Data<Token>>])
+	-> Data<FieldValue>
+{
+	let mut encoded = String::new();
+	for token in tokens {
+		if !encoded.is_empty() {
+			encoded.push_str(", ");
+		}
+		match token.quality {
+			Quality::ONE => encoded.push_str(token.value.as_ref()),
+			quality => encoded.push_str(&format!("{};q={}", token.value, quality))
+		}
+	}
+	Data::try_from(encoded)
+		.expect("Should never fail because all weighted tokens are valid field values")
+}
+/// Gets the quality of the first token in `tokens` that is equal to `value` (case-insensitive)
+/// or of the first wildcard `*` if there is no such token
+pub(in crate::typed) fn token_quality(tokens: &[Weighted<Data<Token>>], value: &str)
+	-> Option<Quality>
+{
+	let exact = tokens.iter().find(|token| token.value.eq_ignore_ascii_case(value.as_bytes()));
+	let wildcard = || tokens.iter().find(|token| &token.value[..] == b"*");
+	exact.or_else(wildcard).map(|token| token.quality)
+}
@@ -0,0 +1,302 @@
+//! Structured URI references according to [RFC 3986](https://tools.ietf.org/html/rfc3986)
+//!
+//! In contrast to the `Uri` encoding, which only validates the character set, this mod validates
+//! the URI grammar and provides access to the single components.
+
+mod normalize;
+mod resolve;
+
+pub use self::{
+	normalize::{ is_equivalent, normalize },
+	resolve::{ remove_dot_segments, resolve }
+};
+
+use crate::{
+	HttpError,
+	data::{
+		Data,
+		encodings::{ Encoding, Uri, UriQuery }
+	},
+	helpers::io_ext::WriteExt
+};
+use std::{
+	str,
+	io::{ self, Cursor },
+	convert::TryFrom,
+	fmt::{ self, Display, Formatter }
+};
+
+
+/// Splits `bytes` at the first occurrence of `separator`
+fn split_once(bytes: &[u8], separator: u8) -> (&[u8], Option<&[u8]>) {
+	match bytes.iter().position(|b| *b == separator) {
+		Some(index) => (&bytes[..index], Some(&bytes[index + 1..])),
+		None => (bytes, None)
+	}
+}
+
+
+/// The userinfo, host and port of an authority
+type AuthorityParts<'a> = (Option<&'a[u8]>, &'a[u8], Option<u16>);
+
+
+/// A URI reference split into its components according to
+/// [RFC 3986](https://tools.ietf.org/html/rfc3986#section-4.1)
+///
+/// A reference is either an absolute URI (with scheme) or a relative reference (without scheme).
+/// The authority components (`userinfo`, `host` and `port`) are only present if the reference
+/// contains an authority; in this case `host` is always set (but may be empty, e.g. in
+/// `file:///etc/hosts`).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParsedUri {
+	pub(in crate::uri) scheme: Option<Data<Uri>>,
+	pub(in crate::uri) userinfo: Option<Data<Uri>>,
+	pub(in crate::uri) host: Option<Data<Uri>>,
+	pub(in crate::uri) port: Option<u16>,
+	pub(in crate::uri) path: Data<Uri>,
+	pub(in crate::uri) query: Option<Data<UriQuery>>,
+	pub(in crate::uri) fragment: Option<Data<UriQuery>>
+}
+impl ParsedUri {
+	/// Parses a URI reference from `bytes`
+	///
+	/// Fails with `HttpError::InvalidEncoding` if `bytes` contain non-URI characters or with
+	/// `HttpError::ProtocolViolation` if `bytes` violate the URI grammar.
+	pub fn parse(bytes: &[u8]) -> Result<Self, HttpError> {
+		if !Uri::is_valid(bytes) {
+			Err(HttpError::InvalidEncoding)?
+		}
+		
+		// Split the fragment and the query
+		let (bytes, fragment) = split_once(bytes, b'#');
+		let (bytes, query) = split_once(bytes, b'?');
+		
+		// Split the scheme (a colon in the first path segment of a relative reference is invalid)
+		let (scheme, bytes) = match bytes.iter().position(|b| *b == b':' || *b == b'/') {
+			Some(colon) if bytes[colon] == b':' => (Some(&bytes[..colon]), &bytes[colon + 1..]),
+			_ => (None, bytes)
+		};
+		if let Some(scheme) = scheme {
+			Self::validate_scheme(scheme)?;
+		}
+		
+		// Split the authority and the path
+		let (authority, path) = match bytes.strip_prefix(b"//") {
+			Some(bytes) => {
+				let authority_len = bytes.iter().position(|b| *b == b'/').unwrap_or(bytes.len());
+				(Some(&bytes[..authority_len]), &bytes[authority_len..])
+			},
+			None => (None, bytes)
+		};
+		let (userinfo, host, port) = match authority {
+			Some(authority) => {
+				let (userinfo, host, port) = Self::parse_authority(authority)?;
+				(userinfo, Some(host), port)
+			},
+			None => (None, None, None)
+		};
+		
+		// Validate the path and create the components
+		if path.iter().any(|b| *b == b'[' || *b == b']') {
+			Err(HttpError::ProtocolViolation)?
+		}
+		Ok(Self {
+			scheme: scheme.map(Data::try_from).transpose()?,
+			userinfo: userinfo.map(Data::try_from).transpose()?,
+			host: host.map(Data::try_from).transpose()?,
+			port,
+			path: Data::try_from(path)?,
+			query: query.map(Self::validate_query).transpose()?,
+			fragment: fragment.map(Self::validate_query).transpose()?
+		})
+	}
+	/// Validates a [scheme](https://tools.ietf.org/html/rfc3986#section-3.1)
+	fn validate_scheme(scheme: &[u8]) -> Result<(), HttpError> {
+		let is_valid = scheme.first().map(u8::is_ascii_alphabetic).unwrap_or(false)
+			&& scheme.iter().all(|b| b.is_ascii_alphanumeric() || b"+-.".contains(b));
+		match is_valid {
+			true => Ok(()),
+			false => Err(HttpError::ProtocolViolation)
+		}
+	}
+	/// Parses an [authority](https://tools.ietf.org/html/rfc3986#section-3.2) into userinfo, host
+	/// and port
+	fn parse_authority(authority: &[u8]) -> Result<AuthorityParts<'_>, HttpError> {
+		// Split the userinfo
+		let (userinfo, host_port) = match authority.iter().position(|b| *b == b'@') {
+			Some(at) => (Some(&authority[..at]), &authority[at + 1..]),
+			None => (None, authority)
+		};
+		if userinfo.unwrap_or_default().iter().any(|b| b"[]".contains(b)) {
+			Err(HttpError::ProtocolViolation)?
+		}
+		
+		// Split the host and the port
+		let (host, port) = match host_port {
+			[b'[', ..] => {
+				let host_len = host_port.iter().position(|b| *b == b']')
+					.ok_or(HttpError::ProtocolViolation)? + 1;
+				Self::validate_ip_literal(&host_port[1..host_len - 1])?;
+				match &host_port[host_len..] {
+					[] => (&host_port[..host_len], None),
+					[b':', port @ ..] => (&host_port[..host_len], Some(port)),
+					_ => Err(HttpError::ProtocolViolation)?
+				}
+			},
+			_ => {
+				let (host, port) = split_once(host_port, b':');
+				if host.iter().any(|b| b"[]@".contains(b)) {
+					Err(HttpError::ProtocolViolation)?
+				}
+				(host, port)
+			}
+		};
+		
+		// Parse the port (an empty port is equal to no port)
+		let port = match port {
+			Some(port) if !port.iter().all(u8::is_ascii_digit) => {
+				Err(HttpError::ProtocolViolation)?
+			},
+			Some(port) if !port.is_empty() => {
+				let port = str::from_utf8(port).expect("Should never fail because digits are ASCII")
+					.parse().map_err(|_| HttpError::ProtocolViolation)?;
+				Some(port)
+			},
+			_ => None
+		};
+		Ok((userinfo, host, port))
+	}
+	/// Validates the contents of an [IP literal](https://tools.ietf.org/html/rfc3986#section-3.2.2)
+	/// (i.e. an IPv6 address or an `IPvFuture`-literal)
+	fn validate_ip_literal(literal: &[u8]) -> Result<(), HttpError> {
+		let is_valid = match literal {
+			[b'v' | b'V', future @ ..] => match split_once(future, b'.') {
+				(version, Some(address)) => {
+					!version.is_empty() && version.iter().all(u8::is_ascii_hexdigit)
+						&& !address.is_empty() && address.iter()
+							.all(|b| Uri::unreserved(*b) || Uri::sub_delims(*b) || *b == b':')
+				},
+				_ => false
+			},
+			address => {
+				address.contains(&b':')
+					&& address.iter().all(|b| b.is_ascii_hexdigit() || *b == b':' || *b == b'.')
+			}
+		};
+		match is_valid {
+			true => Ok(()),
+			false => Err(HttpError::ProtocolViolation)
+		}
+	}
+	/// Validates a [query](https://tools.ietf.org/html/rfc3986#section-3.4) or
+	/// [fragment](https://tools.ietf.org/html/rfc3986#section-3.5)
+	fn validate_query(query: &[u8]) -> Result<Data<UriQuery>, HttpError> {
+		Data::try_from(query).map_err(|_| HttpError::ProtocolViolation)
+	}
+	
+	/// The scheme (e.g. `http`) if any
+	pub fn scheme(&self) -> Option<&Data<Uri>> {
+		self.scheme.as_ref()
+	}
+	/// The userinfo (e.g. `user:password`) if any
+	pub fn userinfo(&self) -> Option<&Data<Uri>> {
+		self.userinfo.as_ref()
+	}
+	/// The host (e.g. `www.example.com` or `[::1]`) if the reference contains an authority
+	pub fn host(&self) -> Option<&Data<Uri>> {
+		self.host.as_ref()
+	}
+	/// The port if any
+	pub fn port(&self) -> Option<u16> {
+		self.port
+	}
+	/// The path (may be empty)
+	pub fn path(&self) -> &Data<Uri> {
+		&self.path
+	}
+	/// The query without the leading `?` if any
+	pub fn query(&self) -> Option<&Data<UriQuery>> {
+		self.query.as_ref()
+	}
+	/// The fragment without the leading `#` if any
+	pub fn fragment(&self) -> Option<&Data<UriQuery>> {
+		self.fragment.as_ref()
+	}
+	/// Checks if the reference is an absolute URI (i.e. has a scheme)
+	pub fn is_absolute(&self) -> bool {
+		self.scheme.is_some()
+	}
+	
+	/// Reassembles the components according to
+	/// [RFC 3986](https://tools.ietf.org/html/rfc3986#section-5.3), writes the URI to `sink` and
+	/// returns the amount of bytes written
+	///
+	/// _Note: an empty port is omitted, so the written URI may differ from the parsed bytes._
+	pub fn write(&self, mut sink: impl WriteExt) -> Result<usize, io::Error> {
+		let mut written = 0;
+		let mut write = |data: &[u8]| -> Result<(), io::Error> {
+			sink.write(data)?;
+			written += data.len();
+			Ok(())
+		};
+		
+		// Write scheme and authority
+		if let Some(scheme) = &self.scheme {
+			write(scheme)?;
+			write(b":")?;
+		}
+		if let Some(host) = &self.host {
+			write(b"//")?;
+			if let Some(userinfo) = &self.userinfo {
+				write(userinfo)?;
+				write(b"@")?;
+			}
+			write(host)?;
+			if let Some(port) = self.port {
+				write(format!(":{}", port).as_bytes())?;
+			}
+		}
+		
+		// Write path, query and fragment
+		write(&self.path)?;
+		if let Some(query) = &self.query {
+			write(b"?")?;
+			write(query)?;
+		}
+		if let Some(fragment) = &self.fragment {
+			write(b"#")?;
+			write(fragment)?;
+		}
+		Ok(written)
+	}
+	/// Reassembles the components into a URI
+	pub fn to_uri(&self) -> Data<Uri> {
+		let mut sink = Cursor::new(Vec::new());
+		self.write(&mut sink).unwrap();
+		Data::try_from(sink.into_inner())
+			.expect("Should never fail because all components are valid URI components")
+	}
+}
+impl Display for ParsedUri {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{}", self.to_uri())
+	}
+}
+impl TryFrom<&[u8]> for ParsedUri {
+	type Error = HttpError;
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		Self::parse(bytes)
+	}
+}
+impl TryFrom<&str> for ParsedUri {
+	type Error = HttpError;
+	fn try_from(string: &str) -> Result<Self, Self::Error> {
+		Self::parse(string.as_bytes())
+	}
+}
+impl TryFrom<&Data<Uri>> for ParsedUri {
+	type Error = HttpError;
+	fn try_from(uri: &Data<Uri>) -> Result<Self, Self::Error> {
+		Self::parse(uri)
+	}
+}
@@ -0,0 +1,132 @@
+use crate::{
+	HttpError,
+	data::{ Data, encodings::{ Encoding, Uri } },
+	uri::{ ParsedUri, remove_dot_segments }
+};
+use std::convert::TryFrom;
+
+
+/// The schemes with a well-known default port
+const DEFAULT_PORTS: &[(&[u8], u16)] = &[
+	(b"http", 80), (b"https", 443), (b"ws", 80), (b"wss", 443), (b"ftp", 21)
+];
+
+
+/// Normalizes the percent-encodings in `bytes` according to
+/// [RFC 3986](https://tools.ietf.org/html/rfc3986#section-6.2.2) (i.e. uses uppercase hex digits
+/// and decodes unreserved characters)
+fn normalize_percent_encoding(bytes: &[u8]) -> Vec<u8> {
+	/// Decodes a single hex digit
+	fn hex(digit: u8) -> u8 {
+		let digit = (digit as char).to_digit(16);
+		digit.expect("Should never fail because the digit has been validated") as u8
+	}
+	
+	let (mut pos, mut normalized) = (0, Vec::with_capacity(bytes.len()));
+	while pos < bytes.len() {
+		match &bytes[pos..] {
+			[b'%', a, b, ..] if Uri::percent_encoding(&bytes[pos..]) => {
+				match hex(*a) << 4 | hex(*b) {
+					decoded if Uri::unreserved(decoded) => normalized.push(decoded),
+					_ => {
+						let (a, b) = (a.to_ascii_uppercase(), b.to_ascii_uppercase());
+						normalized.extend_from_slice(&[b'%', a, b])
+					}
+				}
+				pos += 3;
+			},
+			[b, ..] => {
+				normalized.push(*b);
+				pos += 1;
+			},
+			[] => unreachable!("Should never happen because `pos` is within `bytes`")
+		}
+	}
+	normalized
+}
+/// Normalizes the percent-encodings in `host` (see `normalize_percent_encoding`) and lowercases
+/// the result except for the hex digits of the remaining percent-encodings
+fn normalize_host(host: &[u8]) -> Vec<u8> {
+	let mut normalized = normalize_percent_encoding(host);
+	let mut pos = 0;
+	while pos < normalized.len() {
+		match normalized[pos] {
+			b'%' => pos += 3,
+			b => {
+				normalized[pos] = b.to_ascii_lowercase();
+				pos += 1;
+			}
+		}
+	}
+	normalized
+}
+/// Applies `normalize_percent_encoding` to `data`
+fn normalize_data<E: Encoding>(data: &Data<E>) -> Data<E> {
+	Data::try_from(normalize_percent_encoding(data))
+		.expect("Should never fail because decoding unreserved characters preserves the encoding")
+}
+
+
+/// Normalizes `uri` according to [RFC 3986](https://tools.ietf.org/html/rfc3986#section-6) (see
+/// `ParsedUri::normalize`)
+pub fn normalize(uri: &Data<Uri>) -> Result<Data<Uri>, HttpError> {
+	Ok(ParsedUri::try_from(uri)?.normalize().to_uri())
+}
+/// Checks if `a` and `b` are equivalent after normalization
+pub fn is_equivalent(a: &Data<Uri>, b: &Data<Uri>) -> Result<bool, HttpError> {
+	Ok(ParsedUri::try_from(a)?.is_equivalent(&ParsedUri::try_from(b)?))
+}
+
+
+impl ParsedUri {
+	/// Normalizes the URI according to [RFC 3986](https://tools.ietf.org/html/rfc3986#section-6)
+	///
+	/// This
+	///  - lowercases the scheme and the host
+	///  - uses uppercase hex digits in percent-encodings and decodes percent-encoded unreserved
+	///    characters
+	///  - removes the dot segments from the path of absolute URIs
+	///  - drops the default port and uses `/` instead of an empty path for `http`, `https`, `ws`,
+	///    `wss` and `ftp`
+	pub fn normalize(&self) -> ParsedUri {
+		// Normalize the case and the percent-encodings
+		let scheme = self.scheme.as_ref().map(|scheme| {
+			Data::try_from(scheme.to_ascii_lowercase())
+				.expect("Should never fail because the scheme is ASCII")
+		});
+		let host = self.host.as_ref().map(|host| {
+			Data::try_from(normalize_host(host))
+				.expect("Should never fail because the host is ASCII")
+		});
+		let mut path = normalize_data(&self.path);
+		if scheme.is_some() {
+			path = Data::try_from(remove_dot_segments(&path))
+				.expect("Should never fail because removing dot segments preserves the encoding");
+		}
+		
+		// Apply the scheme-based normalization
+		let default_port = DEFAULT_PORTS.iter()
+			.find(|(default_scheme, _)| scheme.as_deref() == Some(*default_scheme))
+			.map(|(_, port)| *port);
+		let port = match (self.port, default_port) {
+			(Some(port), Some(default_port)) if port == default_port => None,
+			(port, _) => port
+		};
+		if default_port.is_some() && host.is_some() && path.is_empty() {
+			path = Data::try_from(b"/".as_ref())
+				.expect("Should never fail because `/` is a valid path");
+		}
+		
+		ParsedUri {
+			scheme,
+			userinfo: self.userinfo.as_ref().map(normalize_data),
+			host, port, path,
+			query: self.query.as_ref().map(normalize_data),
+			fragment: self.fragment.as_ref().map(normalize_data)
+		}
+	}
+	/// Checks if `self` and `other` are equivalent after normalization
+	pub fn is_equivalent(&self, other: &ParsedUri) -> bool {
+		self.normalize() == other.normalize()
+	}
+}
@@ -0,0 +1,110 @@
+use crate::{
+	HttpError,
+	data::{ Data, encodings::Uri },
+	uri::ParsedUri
+};
+use std::convert::TryFrom;
+
+
+/// Removes the dot segments `.` and `..` from `path` according to
+/// [RFC 3986](https://tools.ietf.org/html/rfc3986#section-5.2.4)
+pub fn remove_dot_segments(path: &[u8]) -> Vec<u8> {
+	/// Removes the last segment and its preceding slash from `output`
+	fn pop(output: &mut Vec<u8>) {
+		let len = output.iter().rposition(|b| *b == b'/').unwrap_or(0);
+		output.truncate(len);
+	}
+	
+	let (mut input, mut output) = (path, Vec::with_capacity(path.len()));
+	while !input.is_empty() {
+		match input {
+			[b'.', b'.', b'/', rest @ ..] | [b'.', b'/', rest @ ..] => input = rest,
+			[b'/', b'.', b'/', ..] => input = &input[2..],
+			[b'/', b'.'] => input = b"/",
+			[b'/', b'.', b'.', b'/', ..] => {
+				input = &input[3..];
+				pop(&mut output);
+			},
+			[b'/', b'.', b'.'] => {
+				input = b"/";
+				pop(&mut output);
+			},
+			[b'.'] | [b'.', b'.'] => input = b"",
+			_ => {
+				// Move the first segment including its leading slash to the output
+				let segment_len = input.iter().skip(1).position(|b| *b == b'/')
+					.map(|len| len + 1).unwrap_or(input.len());
+				output.extend_from_slice(&input[..segment_len]);
+				input = &input[segment_len..];
+			}
+		}
+	}
+	output
+}
+
+
+/// Resolves `reference` against `base` according to
+/// [RFC 3986](https://tools.ietf.org/html/rfc3986#section-5.2) (e.g. `../a?b` against
+/// `http://example.com/x/y/z` becomes `http://example.com/x/a?b`)
+///
+/// Fails with `HttpError::ApiMisuse` if `base` is not an absolute URI.
+pub fn resolve(base: &Data<Uri>, reference: &Data<Uri>) -> Result<Data<Uri>, HttpError> {
+	let base = ParsedUri::try_from(base)?;
+	let reference = ParsedUri::try_from(reference)?;
+	Ok(base.resolve(&reference)?.to_uri())
+}
+
+
+impl ParsedUri {
+	/// Resolves `reference` against `self` as base URI according to
+	/// [RFC 3986](https://tools.ietf.org/html/rfc3986#section-5.2)
+	///
+	/// Fails with `HttpError::ApiMisuse` if `self` is not an absolute URI.
+	pub fn resolve(&self, reference: &ParsedUri) -> Result<ParsedUri, HttpError> {
+		if !self.is_absolute() {
+			Err(HttpError::ApiMisuse)?
+		}
+		
+		// A reference with scheme is taken as is; a reference with authority inherits the scheme
+		let path = |path: &[u8]| Data::try_from(remove_dot_segments(path))
+			.expect("Should never fail because removing dot segments preserves the URI encoding");
+		if reference.is_absolute() {
+			return Ok(ParsedUri{ path: path(&reference.path), ..reference.clone() })
+		}
+		if reference.host.is_some() {
+			return Ok(ParsedUri {
+				scheme: self.scheme.clone(),
+				path: path(&reference.path),
+				..reference.clone()
+			})
+		}
+		
+		// Merge the path and the query
+		let (path, query) = match &reference.path[..] {
+			[] => (self.path.clone(), reference.query.clone().or_else(|| self.query.clone())),
+			[b'/', ..] => (path(&reference.path), reference.query.clone()),
+			_ => (path(&self.merge(&reference.path)), reference.query.clone())
+		};
+		Ok(ParsedUri {
+			scheme: self.scheme.clone(),
+			userinfo: self.userinfo.clone(),
+			host: self.host.clone(),
+			port: self.port,
+			path, query,
+			fragment: reference.fragment.clone()
+		})
+	}
+	/// Merges a relative `path` with the path of `self` according to
+	/// [RFC 3986](https://tools.ietf.org/html/rfc3986#section-5.2.3)
+	fn merge(&self, path: &[u8]) -> Vec<u8> {
+		let mut merged = match (&self.host, &self.path[..]) {
+			(Some(_), []) => b"/".to_vec(),
+			(_, base) => {
+				let base_len = base.iter().rposition(|b| *b == b'/').map(|len| len + 1);
+				base[..base_len.unwrap_or(0)].to_vec()
+			}
+		};
+		merged.extend_from_slice(path);
+		merged
+	}
+}
@@ -0,0 +1,58 @@
+use http_header::data::encodings::{
+	Encoding, Binary, Utf8, Ascii, HeaderFieldKey, FieldValue, Token, Uri, UriQuery, Integer
+};
+
+
+struct Test {
+	first_invalid: fn(&[u8]) -> Option<usize>,
+	is_valid: fn(&[u8]) -> bool,
+	data: &'static[u8],
+	expected: Option<usize>
+}
+impl Test {
+	pub fn test(self) {
+		assert_eq!(self.expected, (self.first_invalid)(self.data));
+		assert_eq!(self.expected.is_none(), (self.is_valid)(self.data));
+	}
+}
+macro_rules! test {
+	($encoding:ty, $data:expr, $expected:expr) => (Test {
+		first_invalid: <$encoding>::first_invalid, is_valid: <$encoding>::is_valid,
+		data: $data, expected: $expected
+	}.test());
+}
+#[test]
+fn test_first_invalid() {
+	test!(Binary, b"\x00\xFF", None);
+	
+	test!(Utf8, "Testö".as_bytes(), None);
+	test!(Utf8, b"Test\xC3", Some(4));
+	
+	test!(Ascii, b"Test 1, 2, 3", None);
+	test!(Ascii, b"Test \x00", Some(5));
+	
+	test!(HeaderFieldKey, b"Content-Type", None);
+	test!(HeaderFieldKey, b"", Some(0));
+	test!(HeaderFieldKey, b"Content Type", Some(7));
+	
+	test!(FieldValue, b"text/html; charset=\"utf-8\"", None);
+	test!(FieldValue, b"M\xFCller", None);
+	test!(FieldValue, b"a\r\nX-Injected: b", Some(1));
+	test!(FieldValue, b"a\x7Fb", Some(1));
+	
+	test!(Token, b"M-SEARCH", None);
+	test!(Token, b"", Some(0));
+	test!(Token, b"GET /", Some(3));
+	
+	test!(Uri, b"/upl%C3%B6ad/form.php?a=b", None);
+	test!(Uri, b"/upl%C3%B6ad/%X", Some(13));
+	test!(Uri, b"/upl%C3%B6ad/<>", Some(13));
+	
+	test!(UriQuery, b"a=b&c=%20", None);
+	test!(UriQuery, b"a=b&c=%2", Some(6));
+	test!(UriQuery, b"a=b#c", Some(3));
+	test!(UriQuery, b"a=/b?c", None);
+	
+	test!(Integer, b"1337", None);
+	test!(Integer, b"13e7", Some(2));
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+#[macro_use] extern crate http_header;
+use http_header::{
+	QueryString,
+	data::{ Data, encodings::Uri }
+};
+use std::convert::TryInto;
+
+
+struct Test {
+	body: &'static[u8],
+	expected: &'static[(&'static str, &'static str)]
+}
+impl Test {
+	pub fn test(self) {
+		let form = QueryString::parse_form(self.body);
+		let expected: Vec<(String, String)> = self.expected.iter()
+			.map(|(k, v)| (k.to_string(), v.to_string())).collect();
+		assert_eq!(expected, form.decoded_pairs());
+		
+		// Reading the body must yield the same form
+		assert_eq!(form, QueryString::read_form(self.body).unwrap());
+	}
+}
+#[test]
+fn test_parse() {
+	Test {
+		body: b"name=John+Doe&city=K%C3%B6ln",
+		expected: &[("name", "John Doe"), ("city", "Köln")]
+	}.test();
+	
+	Test {
+		body: b"a=1&&a=2&b&=c",
+		expected: &[("a", "1"), ("a", "2"), ("b", ""), ("", "c")]
+	}.test();
+	
+	Test{ body: b"x=a=b&y=%2B%26%3D", expected: &[("x", "a=b"), ("y", "+&=")] }.test();
+	
+	Test {
+		body: b"bad=%zz%4&utf8=%FF",
+		expected: &[("bad", "%zz%4"), ("utf8", "\u{FFFD}")]
+	}.test();
+	
+	Test{ body: "raw=ä b/?".as_bytes(), expected: &[("raw", "ä b/?")] }.test();
+	
+	Test{ body: b"", expected: &[] }.test();
+}
+
+
+#[test]
+fn test_serialize() {
+	let mut form = QueryString::new();
+	form.append_decoded("name", "John Doe");
+	form.append_decoded("tags", "a&b=c+d");
+	form.append_decoded("city", "Köln");
+	form.append_decoded("empty", "");
+	form.append_decoded("name", "*-._~");
+	
+	let serialized = "name=John+Doe&tags=a%26b%3Dc%2Bd&city=K%C3%B6ln&empty=&name=*-._%7E";
+	assert_eq!(serialized, form.to_form());
+	assert_eq!(format!("?{}", serialized).replace("empty=", "empty"), form.to_string());
+	assert_eq!(form, QueryString::parse_form(form.to_form().as_bytes()));
+	
+	assert_eq!(Some("John Doe".to_string()), form.get_decoded("name"));
+	assert_eq!(Some("Köln".to_string()), form.get_decoded("city"));
+	assert_eq!(None, form.get_decoded("missing"));
+	assert_eq!(2, form.remove(&data!("name")).len());
+	assert_eq!(None, form.get_decoded("name"));
+}
+
+
+#[test]
+fn test_query_string() {
+	let uri: Data<Uri> = data!("/search?q=tree+-swing&lang=de%2Dat&flag");
+	let query: QueryString = uri.try_into().unwrap();
+	
+	assert_eq!(Some("tree -swing".to_string()), query.get_decoded("q"));
+	assert_eq!(Some("de-at".to_string()), query.get_decoded("lang"));
+	assert_eq!(Some(String::new()), query.get_decoded("flag"));
+	assert_eq!("q=tree+-swing&lang=de-at&flag=", query.to_form());
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+#[macro_use] extern crate http_header;
+use http_header::{
+	HttpError,
+	data::{ Data, encodings::{ Uri, UriQuery }, percent::EncodeSet }
+};
+
+
+struct TestEncode {
+	input: &'static str,
+	set: EncodeSet,
+	expected: &'static str
+}
+impl TestEncode {
+	pub fn test(self) {
+		let encoded: Data<Uri> = Data::percent_encode(self.input, self.set);
+		assert_eq!(self.expected, encoded.to_string(), "{}", self.input);
+		
+		// Decoding must restore the input
+		assert_eq!(self.input, encoded.percent_decode_utf8().unwrap().to_string());
+	}
+}
+#[test]
+fn test_encode() {
+	TestEncode{ input: "a/b c", set: EncodeSet::PathSegment, expected: "a%2Fb%20c" }.test();
+	TestEncode{ input: "~user:x@y", set: EncodeSet::PathSegment, expected: "~user:x@y" }.test();
+	TestEncode{ input: "a=b&c+d/?", set: EncodeSet::Query, expected: "a%3Db%26c%2Bd/?" }.test();
+	TestEncode{ input: "a=b&c/?#", set: EncodeSet::Fragment, expected: "a=b&c/?%23" }.test();
+	TestEncode{ input: "us@r:pw/", set: EncodeSet::Userinfo, expected: "us%40r:pw%2F" }.test();
+	TestEncode{ input: "Grüße", set: EncodeSet::PathSegment, expected: "Gr%C3%BC%C3%9Fe" }.test();
+	TestEncode{ input: "", set: EncodeSet::Query, expected: "" }.test();
+}
+
+
+#[test]
+fn test_decode() {
+	let query: Data<UriQuery> = data!("a+b%3d%C3%A4");
+	assert_eq!("a+b=ä", query.percent_decode_utf8().unwrap().to_string());
+	
+	let uri: Data<Uri> = data!("/%FF%00");
+	assert_eq!(b"/\xFF\x00".as_ref(), &uri.percent_decode()[..]);
+}
+
+
+#[test]
+fn test_decode_err() {
+	let uri: Data<Uri> = data!("/%C3");
+	assert_eq!(HttpError::InvalidUtf8, uri.percent_decode_utf8().unwrap_err());
+	
+	let query: Data<UriQuery> = data!("%FF");
+	assert_eq!(HttpError::InvalidUtf8, query.percent_decode_utf8().unwrap_err());
+}
@@ -6,35 +6,33 @@ use http_header::{
 		encodings::{ Uri, UriQuery }
 	}
 };
-use std::{ collections::HashMap, convert::TryInto };
+use std::convert::{ TryFrom, TryInto };
 
 
-macro_rules! map {
-	($($key:expr => $value:expr),+) => ({
-		let mut map = ::std::collections::HashMap::new();
-		$( map.insert(data!($key), data!($value)); )*
-		map
-	});
-	() => (::std::collections::HashMap::new());
+macro_rules! pairs {
+	($($key:expr => $value:expr),+) => (vec![ $( (data!($key), data!($value)) ),* ]);
+	() => (Vec::new());
 }
 
 
 struct Test {
 	uri: &'static[u8],
-	expected: HashMap<Data<UriQuery>, Data<UriQuery>>
+	expected: Vec<(Data<UriQuery>, Data<UriQuery>)>
 }
 impl Test {
 	pub fn test(self) {
 		let uri: Data<Uri> = self.uri.try_into().unwrap();
 		let query: QueryString = uri.try_into().unwrap();
-		assert_eq!(&self.expected, query.fields());
+		
+		let pairs: Vec<_> = query.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+		assert_eq!(self.expected, pairs);
 	}
 }
 #[test]
 fn test() {
 	Test {
 		uri: b"/?code=M696be062-f150-bb19-9944-0c3a0ca60b48&state=99f4bd624dbe53d0ae330eabda904ac4",
-		expected: map!(
+		expected: pairs!(
 			"code" => "M696be062-f150-bb19-9944-0c3a0ca60b48",
 			"state" => "99f4bd624dbe53d0ae330eabda904ac4"
 		)
@@ -45,19 +43,81 @@ fn test() {
 			"/secure.flickr.com/search/",
 			"?q=tree+-swing&l=commderiv&d=taken-20000101-20051231&ct=0&lol&mt=all&adv=1&&"
 		).as_bytes(),
-		expected: map!(
+		expected: pairs!(
 			"q" => "tree+-swing",
 			"l" => "commderiv",
 			"d" => "taken-20000101-20051231",
 			"ct" => "0",
+			"lol" => "",
 			"mt" => "all",
-			"adv" => "1",
-			"lol" => ""
+			"adv" => "1"
 		)
 	}.test();
 	
-	Test{ uri: b"/sth/?", expected: map!() }.test();
-	Test{ uri: b"/sth/", expected: map!() }.test();
+	Test {
+		uri: b"/?tag=b&x=1&tag=a#tag=c",
+		expected: pairs!("tag" => "b", "x" => "1", "tag" => "a")
+	}.test();
+	
+	// `/` and `?` are valid query characters (RFC 3986, section 3.4)
+	Test {
+		uri: b"/login?next=/a/b?c&x=1",
+		expected: pairs!("next" => "/a/b?c", "x" => "1")
+	}.test();
+	
+	Test{ uri: b"/sth/?", expected: pairs!() }.test();
+	Test{ uri: b"/sth/", expected: pairs!() }.test();
+}
+
+
+#[test]
+fn test_multi_valued() {
+	let uri: Data<Uri> = data!("/?tag=a&x=1&tag=b&y");
+	let mut query: QueryString = uri.try_into().unwrap();
+	let (tag, x, y): (Data<UriQuery>, Data<UriQuery>, Data<UriQuery>) =
+		(data!("tag"), data!("x"), data!("y"));
+	
+	assert_eq!(Some(&data!("a")), query.get(&tag));
+	assert_eq!(vec!["a", "b"], query.get_all(&tag).map(|v| v.to_string()).collect::<Vec<_>>());
+	assert_eq!(4, query.len());
+	
+	query.append(tag.clone(), data!("c"));
+	assert_eq!("?tag=a&x=1&tag=b&y&tag=c", query.to_string());
+	
+	query.insert(x.clone(), data!("2"));
+	query.insert(data!("z"), data!("3"));
+	assert_eq!("?tag=a&x=2&tag=b&y&tag=c&z=3", query.to_string());
+	
+	query.insert(tag.clone(), data!("d"));
+	assert_eq!("?tag=d&x=2&y&z=3", query.to_string());
+	
+	assert_eq!(vec![Data::<UriQuery>::try_from("").unwrap()], query.remove(&y));
+	assert!(query.remove(&y).is_empty());
+	assert!(!query.contains_key(&y));
+	assert_eq!("?tag=d&x=2&z=3", query.to_string());
 }
 
 
+#[test]
+fn test_stable_serialization() {
+	let uri: Data<Uri> = data!("/?b=2&a=1&c=3&a=0");
+	let query: QueryString = uri.try_into().unwrap();
+	for _ in 0..16 {
+		assert_eq!("?b=2&a=1&c=3&a=0", query.clone().to_string());
+	}
+}
+
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_accessors() {
+	let uri: Data<Uri> = data!("/?tag=a&x=1&tag=b");
+	let mut query: QueryString = uri.try_into().unwrap();
+	let tag: Data<UriQuery> = data!("tag");
+	
+	assert_eq!(Some(&data!("a")), query.field(&tag));
+	*query.field_mut(&tag).unwrap() = data!("c");
+	assert_eq!(3, query.fields().count());
+	query.fields_mut().for_each(|(_, v)| *v = data!("0"));
+	assert_eq!("?tag=0&x=0&tag=0", query.to_string());
+}
\ No newline at end of file
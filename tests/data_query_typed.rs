@@ -0,0 +1,112 @@
+#[macro_use] extern crate http_header;
+use http_header::{
+	FromQuery, FromQueryValue, HttpError, QueryError, QueryString,
+	data::{ Data, encodings::Uri }
+};
+use std::{ fmt::Debug, convert::TryInto };
+
+
+fn query(uri: &str) -> QueryString {
+	let uri: Data<Uri> = data!(uri);
+	uri.try_into().unwrap()
+}
+
+
+struct Test<T: FromQueryValue + Debug + PartialEq> {
+	uri: &'static str,
+	name: &'static str,
+	expected: Result<T, (HttpError, &'static str)>
+}
+impl<T: FromQueryValue + Debug + PartialEq> Test<T> {
+	pub fn test(self) {
+		let name = self.name.to_string();
+		let expected = self.expected.map_err(|(kind, reason)| QueryError{ kind, name, reason });
+		assert_eq!(expected, query(self.uri).get_as::<T>(self.name), "{}", self.uri);
+	}
+}
+#[test]
+fn test_get_as() {
+	Test{ uri: "/?page=7", name: "page", expected: Ok(7u32) }.test();
+	Test{ uri: "/?page=-7&page=8", name: "page", expected: Ok(-7i8) }.test();
+	Test{ uri: "/?x=1.5e3", name: "x", expected: Ok(1500f64) }.test();
+	Test{ uri: "/?a=on", name: "a", expected: Ok(true) }.test();
+	Test{ uri: "/?a=false", name: "a", expected: Ok(false) }.test();
+	Test{ uri: "/?s=K%C3%B6ln", name: "s", expected: Ok("Köln".to_string()) }.test();
+	Test{ uri: "/?my%20key=a+b", name: "my key", expected: Ok("a b".to_string()) }.test();
+	Test{ uri: "/?my+key=a%2Bb", name: "my key", expected: Ok("a+b".to_string()) }.test();
+	Test{ uri: "/?x", name: "x", expected: Ok(String::new()) }.test();
+	
+	Test{ uri: "/?page=2", name: "page", expected: Ok(Some(2u8)) }.test();
+	Test{ uri: "/?other=2", name: "page", expected: Ok(None::<u8>) }.test();
+	Test{ uri: "/?tag=1&x=y&tag=2", name: "tag", expected: Ok(vec![1u16, 2]) }.test();
+	Test{ uri: "/", name: "tag", expected: Ok(Vec::<u16>::new()) }.test();
+	
+	Test {
+		uri: "/?other=2", name: "page",
+		expected: Err::<u32, _>((HttpError::InvalidParameter, "the parameter is missing"))
+	}.test();
+	Test {
+		uri: "/?page=two", name: "page",
+		expected: Err::<u32, _>((HttpError::InvalidParameter, "the value is not an integer"))
+	}.test();
+	Test {
+		uri: "/?page=256", name: "page",
+		expected: Err::<Option<u8>, _>((HttpError::InvalidParameter, "the integer is out of range"))
+	}.test();
+	Test {
+		uri: "/?tag=1&tag=x", name: "tag",
+		expected: Err::<Vec<u8>, _>((HttpError::InvalidParameter, "the value is not an integer"))
+	}.test();
+	Test {
+		uri: "/?a=yes", name: "a",
+		expected: Err::<bool, _>((HttpError::InvalidParameter, "the value is not a boolean"))
+	}.test();
+	Test {
+		uri: "/?x=1,5", name: "x",
+		expected: Err::<f32, _>((HttpError::InvalidParameter, "the value is not a number"))
+	}.test();
+	Test {
+		uri: "/?s=%FF", name: "s",
+		expected: Err::<String, _>((HttpError::InvalidUtf8, "the decoded value is not valid UTF-8"))
+	}.test();
+}
+
+
+#[test]
+fn test_get_as_form() {
+	// Typed extraction must decode like `get_decoded`
+	let form = QueryString::parse_form(b"q=hello+world&r=hello%20world&s=a%2Bb&my+key=1");
+	for name in ["q", "r"].iter() {
+		assert_eq!(Ok("hello world".to_string()), form.get_as::<String>(name));
+		assert_eq!(form.get_decoded(name), form.get_as::<String>(name).ok());
+	}
+	assert_eq!(Ok("a+b".to_string()), form.get_as::<String>("s"));
+	assert_eq!(Ok(1u8), form.get_as::<u8>("my key"));
+}
+
+
+#[derive(Debug, PartialEq)]
+struct Search {
+	q: String,
+	page: Option<u32>,
+	tags: Vec<String>
+}
+impl FromQuery for Search {
+	fn from_query(query: &QueryString) -> Result<Self, QueryError> {
+		Ok(Self{ q: query.get_as("q")?, page: query.get_as("page")?, tags: query.get_as("tag")? })
+	}
+}
+#[test]
+fn test_extract() {
+	let search: Search = query("/search?q=rust&tag=a&tag=b").extract().unwrap();
+	let tags = vec!["a".to_string(), "b".to_string()];
+	assert_eq!(Search{ q: "rust".to_string(), page: None, tags }, search);
+	
+	let error = query("/search?q=rust&page=x").extract::<Search>().unwrap_err();
+	assert_eq!("page", error.name);
+	assert_eq!(HttpError::InvalidParameter, HttpError::from(error.clone()));
+	assert_eq!(
+		"InvalidParameter for query parameter \"page\": the value is not an integer",
+		error.to_string()
+	);
+}
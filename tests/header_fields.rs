@@ -0,0 +1,87 @@
+#[macro_use] extern crate http_header;
+use http_header::{
+	HttpError, HeaderFields,
+	data::{
+		Data,
+		encodings::{ FieldValue, HeaderFieldKey }
+	}
+};
+
+
+/// Creates a field store from `(key, value)`-pairs
+fn fields(pairs: &[(&str, &str)]) -> HeaderFields {
+	pairs.iter().map(|(k, v)| (data!(*k), data!(*v))).collect()
+}
+
+
+#[test]
+fn test_get() {
+	let fields = fields(&[
+		("Via", "1.0 fred"), ("Host", "example.com"), ("via", "1.1 p.example.net")
+	]);
+	let via: Data<HeaderFieldKey> = data!("VIA");
+	
+	assert_eq!("1.0 fred", fields.get(&via).unwrap());
+	assert_eq!(None, fields.get(&data!("Server")));
+	
+	let all: Vec<&Data<FieldValue>> = fields.get_all(&via).collect();
+	assert_eq!(2, all.len());
+	assert_eq!("1.0 fred", all[0]);
+	assert_eq!("1.1 p.example.net", all[1]);
+	assert_eq!(0, fields.get_all(&data!("Server")).count());
+	
+	assert_eq!(3, fields.len());
+	assert_eq!(3, fields.iter().count());
+}
+
+
+#[test]
+fn test_insert_append_remove() {
+	let mut fields = HeaderFields::new();
+	let cookie: Data<HeaderFieldKey> = data!("Set-Cookie");
+	
+	fields.append(cookie.clone(), data!("a=1"));
+	fields.append(cookie.clone(), data!("b=2"));
+	assert_eq!(2, fields.get_all(&cookie).count());
+	
+	fields.insert(cookie.clone(), data!("c=3"));
+	assert_eq!(1, fields.get_all(&cookie).count());
+	assert_eq!("c=3", fields.get(&cookie).unwrap());
+	
+	let removed = fields.remove(&cookie);
+	assert_eq!(1, removed.len());
+	assert!(!fields.contains_key(&cookie));
+	assert!(fields.is_empty());
+}
+
+
+#[test]
+fn test_get_combined() {
+	let fields = fields(&[
+		("Cache-Control", "no-cache"), ("Cache-Control", "no-store"),
+		("Set-Cookie", "a=1"), ("Set-Cookie", "b=2"), ("Host", "example.com")
+	]);
+	
+	let combined = fields.get_combined(&data!("cache-control")).unwrap().unwrap();
+	assert_eq!("no-cache, no-store", &combined);
+	
+	let combined = fields.get_combined(&data!("Host")).unwrap().unwrap();
+	assert_eq!("example.com", &combined);
+	
+	assert_eq!(None, fields.get_combined(&data!("Server")).unwrap());
+	assert_eq!(HttpError::ApiMisuse, fields.get_combined(&data!("Set-Cookie")).unwrap_err());
+}
+
+
+#[test]
+fn test_order() {
+	let mut fields = fields(&[
+		("Host", "example.com"), ("Set-Cookie", "a=1"), ("Accept", "*/*"), ("Set-Cookie", "b=2")
+	]);
+	fields.insert(data!("set-cookie"), data!("c=3"));
+	fields.append(data!("Connection"), data!("close"));
+	
+	let expected = ["Host: example.com", "set-cookie: c=3", "Accept: */*", "Connection: close"];
+	let pairs: Vec<String> = fields.iter().map(|(k, v)| format!("{}: {}", k, v)).collect();
+	assert_eq!(&expected, pairs.as_slice());
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use http_header::{ HttpError, Header, RequestHeader };
+use std::convert::TryInto;
+
+
+struct Test {
+	fields: &'static str,
+	e: Option<HttpError>
+}
+impl Test {
+	fn test(self) {
+		let data = format!("POST / HTTP/1.1\r\nHost: www.heise.de\r\n{}\r\n", self.fields);
+		let header: RequestHeader = Header::parse(data.as_bytes()).unwrap().try_into().unwrap();
+		assert_eq!(self.e, header.validate_hardened().err());
+	}
+}
+#[test]
+fn test() {
+	Test{ fields: "", e: None }.test();
+	Test{ fields: "Content-Length: 42\r\n", e: None }.test();
+	Test{ fields: "Content-Length: 42\r\ncontent-length: 42\r\n", e: None }.test();
+	Test{ fields: "Content-Length: 42, 42\r\n", e: None }.test();
+	Test{ fields: "Transfer-Encoding: chunked\r\n", e: None }.test();
+	Test{ fields: "Transfer-Encoding: gzip, CHUNKED\r\n", e: None }.test();
+	Test{ fields: "Transfer-Encoding: gzip\r\nTransfer-Encoding: chunked\r\n", e: None }.test();
+	
+	Test {
+		fields: "Content-Length: 42\r\nTransfer-Encoding: chunked\r\n",
+		e: Some(HttpError::AmbiguousFraming)
+	}.test();
+	Test {
+		fields: "Content-Length: 42\r\nContent-Length: 43\r\n",
+		e: Some(HttpError::ConflictingContentLength)
+	}.test();
+	Test {
+		fields: "Content-Length: 42, 7\r\n",
+		e: Some(HttpError::ConflictingContentLength)
+	}.test();
+	Test{ fields: "Content-Length: +42\r\n", e: Some(HttpError::InvalidContentLength) }.test();
+	Test{ fields: "Content-Length: 0x2A\r\n", e: Some(HttpError::InvalidContentLength) }.test();
+	Test{ fields: "Content-Length: 42,\r\n", e: Some(HttpError::InvalidContentLength) }.test();
+	Test{ fields: "Content-Length:\r\n", e: Some(HttpError::InvalidContentLength) }.test();
+	Test {
+		fields: "Content-Length: 99999999999999999999999\r\n",
+		e: Some(HttpError::InvalidContentLength)
+	}.test();
+	Test {
+		fields: "Transfer-Encoding: chunked, gzip\r\n",
+		e: Some(HttpError::ChunkedNotFinal)
+	}.test();
+	Test {
+		fields: "Transfer-Encoding: chunked, chunked\r\n",
+		e: Some(HttpError::ChunkedNotFinal)
+	}.test();
+	Test{ fields: "Transfer-Encoding: gzip\r\n", e: Some(HttpError::ChunkedNotFinal) }.test();
+	Test{ fields: "Transfer-Encoding: xchunked\r\n", e: Some(HttpError::ChunkedNotFinal) }.test();
+}
+
+
+#[test]
+fn test_whitespace_before_colon() {
+	let e = Header::parse(b"POST / HTTP/1.1\r\nContent-Length : 42\r\n\r\n").unwrap_err();
+	assert_eq!(HttpError::WhitespaceBeforeColon, e);
+	
+	let e = Header::parse(b"POST / HTTP/1.1\r\nTransfer-Encoding\t: chunked\r\n\r\n").unwrap_err();
+	assert_eq!(HttpError::WhitespaceBeforeColon, e);
+}
\ No newline at end of file
@@ -0,0 +1,111 @@
+use http_header::{
+	HttpError, Header, HeaderRef, Method, RequestBuilder, RequestHeader, RequestHeaderRef, data
+};
+use std::convert::{ TryFrom, TryInto };
+
+
+struct Test {
+	method: &'static str,
+	expected: Method,
+	safe: bool,
+	idempotent: bool,
+	cacheable: bool
+}
+impl Test {
+	pub fn test(self) {
+		let method = Method::try_from(self.method).unwrap();
+		assert_eq!(self.expected, method);
+		assert_eq!(self.method, method.as_str());
+		assert_eq!(self.method, method.to_string());
+		assert_eq!(self.safe, method.is_safe());
+		assert_eq!(self.idempotent, method.is_idempotent());
+		assert_eq!(self.cacheable, method.is_cacheable());
+	}
+}
+#[test]
+fn test() {
+	Test {
+		method: "GET", expected: Method::Get,
+		safe: true, idempotent: true, cacheable: true
+	}.test();
+	Test {
+		method: "HEAD", expected: Method::Head,
+		safe: true, idempotent: true, cacheable: true
+	}.test();
+	Test {
+		method: "POST", expected: Method::Post,
+		safe: false, idempotent: false, cacheable: true
+	}.test();
+	Test {
+		method: "PUT", expected: Method::Put,
+		safe: false, idempotent: true, cacheable: false
+	}.test();
+	Test {
+		method: "DELETE", expected: Method::Delete,
+		safe: false, idempotent: true, cacheable: false
+	}.test();
+	Test {
+		method: "CONNECT", expected: Method::Connect,
+		safe: false, idempotent: false, cacheable: false
+	}.test();
+	Test {
+		method: "OPTIONS", expected: Method::Options,
+		safe: true, idempotent: true, cacheable: false
+	}.test();
+	Test {
+		method: "TRACE", expected: Method::Trace,
+		safe: true, idempotent: true, cacheable: false
+	}.test();
+	Test {
+		method: "PATCH", expected: Method::Patch,
+		safe: false, idempotent: false, cacheable: false
+	}.test();
+	Test {
+		method: "M-SEARCH", expected: Method::Extension(data!("M-SEARCH")),
+		safe: false, idempotent: false, cacheable: false
+	}.test();
+	Test {
+		method: "get", expected: Method::Extension(data!("get")),
+		safe: false, idempotent: false, cacheable: false
+	}.test();
+	
+	// Extension tokens that spell a standard method are equal to the standard method
+	assert_eq!(Method::Get, Method::Extension(data!("GET")));
+}
+
+
+#[test]
+fn test_err() {
+	assert_eq!(HttpError::InvalidEncoding, Method::try_from("").unwrap_err());
+	assert_eq!(HttpError::InvalidEncoding, Method::try_from("GET /").unwrap_err());
+	assert_eq!(HttpError::InvalidEncoding, Method::try_from("G(E)T").unwrap_err());
+}
+
+
+#[test]
+fn test_header() {
+	const DATA: &[u8] = b"PATCH /index.html HTTP/1.1\r\nHost: example.com\r\n\r\n";
+	let header: RequestHeader = Header::parse(DATA).unwrap().try_into().unwrap();
+	assert_eq!(&Method::Patch, header.method());
+	assert!(!header.method().is_idempotent());
+	
+	let built = RequestBuilder::new()
+		.method(Method::Patch)
+		.uri(data!("/index.html"))
+		.version(data!("HTTP/1.1"))
+		.field(data!("Host"), data!("example.com"))
+		.build().unwrap();
+	assert_eq!(&Method::Patch, built.method());
+	assert_eq!(DATA, built.to_vec().as_slice());
+}
+
+
+#[test]
+fn test_header_err() {
+	const DATA: &[u8] = b"G(E)T /index.html HTTP/1.1\r\n\r\n";
+	let header = Header::parse(DATA).unwrap();
+	assert_eq!(HttpError::InvalidEncoding, RequestHeader::try_from(header).unwrap_err());
+	
+	let header = HeaderRef::parse(DATA).unwrap();
+	assert_eq!(HttpError::InvalidEncoding, RequestHeaderRef::try_from(header).unwrap_err());
+}
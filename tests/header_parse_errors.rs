@@ -0,0 +1,108 @@
+use http_header::{ HttpError, Component, Header, ParserConfig, ParseLimits };
+
+
+struct TestErr {
+	data: &'static[u8],
+	kind: HttpError,
+	offset: usize,
+	line: usize,
+	component: Component
+}
+impl TestErr {
+	fn test(self) {
+		let limits = ParseLimits{ max_fields: 2, ..ParseLimits::default() };
+		let e = Header::parse_with(self.data, &ParserConfig{ limits, ..Default::default() }).unwrap_err();
+		
+		assert_eq!(self.kind, e.kind);
+		assert_eq!(self.offset, e.offset);
+		assert_eq!(self.line, e.line);
+		assert_eq!(self.component, e.component);
+		assert!(!e.reason.is_empty());
+	}
+}
+#[test]
+fn test_err() {
+	TestErr {
+		data: b"HEAD / HTTP/1.1\r\n",
+		kind: HttpError::TruncatedData, offset: 17, line: 2, component: Component::Header
+	}.test();
+	TestErr {
+		data: b"HEAD / \r\n\r\n",
+		kind: HttpError::ProtocolViolation, offset: 0, line: 1, component: Component::StatusLine
+	}.test();
+	TestErr {
+		data: b"H\xC3\xA4D / HTTP/1.1\r\n\r\n",
+		kind: HttpError::InvalidEncoding, offset: 1, line: 1, component: Component::Method
+	}.test();
+	TestErr {
+		data: b"HEAD /l\xC3\xB6l HTTP/1.1\r\n\r\n",
+		kind: HttpError::InvalidEncoding, offset: 7, line: 1, component: Component::Uri
+	}.test();
+	TestErr {
+		data: b"HEAD / HTT\xC3\x9F/1.1\r\n\r\n",
+		kind: HttpError::InvalidEncoding, offset: 10, line: 1, component: Component::Version
+	}.test();
+	TestErr {
+		data: b"HTTP/1.1 200 \x7FK\r\n\r\n",
+		kind: HttpError::InvalidEncoding, offset: 13, line: 1, component: Component::Reason
+	}.test();
+	
+	TestErr {
+		data: concat!(
+			"HEAD / HTTP/1.1\r\n",
+			"Host: www.heise.de\r\n",
+			"User-Agent \r\n",
+			"\r\n"
+		).as_bytes(),
+		kind: HttpError::ProtocolViolation, offset: 37, line: 3, component: Component::Field
+	}.test();
+	TestErr {
+		data: concat!(
+			"HEAD / HTTP/1.1\r\n",
+			"Host: www.heise.de\r\n",
+			"User Agent: http_header/0.3.0\r\n",
+			"\r\n"
+		).as_bytes(),
+		kind: HttpError::InvalidEncoding, offset: 41, line: 3, component: Component::FieldName
+	}.test();
+	TestErr {
+		data: b"HEAD / HTTP/1.1\r\nHost: www.h\x7Fise.de\r\n\r\n",
+		kind: HttpError::InvalidEncoding, offset: 28, line: 2, component: Component::FieldValue
+	}.test();
+	
+	// A stray CR or FF is not optional whitespace and must not be trimmed
+	TestErr {
+		data: b"HEAD / HTTP/1.1\r\nHost: x\r\r\n\r\n",
+		kind: HttpError::InvalidEncoding, offset: 24, line: 2, component: Component::FieldValue
+	}.test();
+	TestErr {
+		data: b"HEAD / HTTP/1.1\r\nHost: \x0Cx\r\n\r\n",
+		kind: HttpError::InvalidEncoding, offset: 23, line: 2, component: Component::FieldValue
+	}.test();
+	TestErr {
+		data: b"HEAD / HTTP/1.1\r\r\n\r\n",
+		kind: HttpError::InvalidEncoding, offset: 15, line: 1, component: Component::Version
+	}.test();
+	TestErr {
+		data: concat!(
+			"HEAD / HTTP/1.1\r\n",
+			"Host: www.heise.de\r\n",
+			"Accept: */*\r\n",
+			"Connection: close\r\n",
+			"\r\n"
+		).as_bytes(),
+		kind: HttpError::TooManyFields, offset: 50, line: 4, component: Component::Field
+	}.test();
+}
+
+
+#[test]
+fn test_display() {
+	let e = Header::parse_with(b"HEAD / \r\n\r\n", &ParserConfig::default()).unwrap_err();
+	assert_eq!(
+		"ProtocolViolation at line 1, byte 0 (StatusLine): \
+			status line must consist of three space-separated parts",
+		e.to_string()
+	);
+	assert_eq!(HttpError::ProtocolViolation, HttpError::from(e));
+}
\ No newline at end of file
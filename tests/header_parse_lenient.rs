@@ -0,0 +1,183 @@
+use http_header::{
+	Component, HttpError, Header, HeaderParser, HeaderRef, ParseStatus, ParserConfig, RequestHeader
+};
+use std::{ convert::TryInto, io::Cursor };
+
+
+struct Test {
+	data: &'static[u8],
+	fields: &'static[(&'static str, &'static str)]
+}
+impl Test {
+	pub fn test(self) {
+		let header: RequestHeader = Header::parse_with(self.data, &ParserConfig::lenient()).unwrap()
+			.try_into().unwrap();
+		assert_eq!("GET", header.method());
+		assert_eq!("/", header.uri());
+		assert_eq!("HTTP/1.1", header.version());
+		
+		let fields: Vec<_> = header.fields().iter()
+			.map(|(k, v)| (k.to_string(), v.to_string()))
+			.collect();
+		let expected: Vec<_> = self.fields.iter()
+			.map(|(k, v)| (k.to_string(), v.to_string()))
+			.collect();
+		assert_eq!(expected, fields);
+	}
+}
+#[test]
+fn test() {
+	Test {
+		data: b"GET / HTTP/1.1\nHost: example.com\nAccept: */*\n\n",
+		fields: &[("Host", "example.com"), ("Accept", "*/*")]
+	}.test();
+	
+	Test {
+		data: b"GET / HTTP/1.1\r\nHost: example.com\nAccept: */*\r\n\n",
+		fields: &[("Host", "example.com"), ("Accept", "*/*")]
+	}.test();
+	
+	Test {
+		data: b"GET / HTTP/1.1\r\nX-Folded: first\r\n  second\r\n\tthird \r\nHost: example.com\r\n\r\n",
+		fields: &[("X-Folded", "first second third"), ("Host", "example.com")]
+	}.test();
+	
+	Test {
+		data: b"GET / HTTP/1.1\nX-Folded:\n  only\nX-Empty: value\n \n\n",
+		fields: &[("X-Folded", "only"), ("X-Empty", "value")]
+	}.test();
+}
+
+
+struct TestErr {
+	data: &'static[u8],
+	config: ParserConfig,
+	kind: HttpError,
+	component: Component
+}
+impl TestErr {
+	pub fn test(self) {
+		let e = Header::parse_with(self.data, &self.config).unwrap_err();
+		assert_eq!(self.kind, e.kind, "{}", e);
+		assert_eq!(self.component, e.component, "{}", e);
+	}
+}
+#[test]
+fn test_err() {
+	TestErr {
+		data: b"GET / HTTP/1.1\r\nX-Folded: first\r\n second\r\n\r\n",
+		config: ParserConfig::default(),
+		kind: HttpError::ProtocolViolation,
+		component: Component::Field
+	}.test();
+	
+	TestErr {
+		data: b"GET / HTTP/1.1\r\n folded\r\n\r\n",
+		config: ParserConfig::lenient(),
+		kind: HttpError::ProtocolViolation,
+		component: Component::Field
+	}.test();
+	
+	TestErr {
+		data: b"GET / HTTP/1.1\nHost: example.com\n\n",
+		config: ParserConfig::default(),
+		kind: HttpError::TruncatedData,
+		component: Component::Header
+	}.test();
+	
+	TestErr {
+		data: b"GET / HTTP/1.1\r\nX-Folded: first\r\n sec\x7Fond\r\n\r\n",
+		config: ParserConfig::lenient(),
+		kind: HttpError::InvalidEncoding,
+		component: Component::FieldValue
+	}.test();
+	
+	TestErr {
+		data: b"GET / HTTP/1.1\r\nX-Folded: first\r\n second\r\r\n\r\n",
+		config: ParserConfig::lenient(),
+		kind: HttpError::InvalidEncoding,
+		component: Component::FieldValue
+	}.test();
+	
+	TestErr {
+		data: b"GET / HTTP/1.1\r\nX-Folded: first\r\n second\r\n\r\n",
+		config: ParserConfig {
+			allow_bare_lf: true,
+			..Default::default()
+		},
+		kind: HttpError::ProtocolViolation,
+		component: Component::Field
+	}.test();
+}
+
+
+#[test]
+fn test_field_line_len() {
+	let mut config = ParserConfig::lenient();
+	config.limits.max_field_line_len = 16;
+	
+	let data = b"GET / HTTP/1.1\r\nX-Folded: first\r\n second\r\n\r\n";
+	let e = Header::parse_with(data, &config).unwrap_err();
+	assert_eq!(HttpError::FieldLineTooLong, e.kind);
+	
+	// The physical lines are measured without the interior line breaks
+	for data in [data.as_ref(), b"GET / HTTP/1.1\nX-Folded: first\n second\n\n"] {
+		config.limits.max_field_line_len = 22;
+		assert!(Header::parse_with(data, &config).is_ok());
+		
+		config.limits.max_field_line_len = 21;
+		let e = Header::parse_with(data, &config).unwrap_err();
+		assert_eq!(HttpError::FieldLineTooLong, e.kind);
+	}
+}
+
+
+#[test]
+fn test_ref() {
+	let data = b"GET / HTTP/1.1\nHost: example.com\nX-Folded: a\n b\n\n";
+	let header = HeaderRef::parse_with(data, &ParserConfig::lenient()).unwrap();
+	assert_eq!(Some(b"example.com".as_ref()), header.field("host"));
+	assert_eq!(Some(b"a b".as_ref()), header.field("X-Folded"));
+}
+
+
+#[test]
+fn test_read() {
+	const DATA: &[u8] = b"GET / HTTP/1.1\nHost: example.com\n\nBody";
+	const HEADER_LEN: usize = DATA.len() - b"Body".len();
+	let config = ParserConfig::lenient();
+	
+	let (header, body) = Header::scan_with(DATA, &config).unwrap();
+	assert_eq!((&DATA[..HEADER_LEN], b"Body".as_ref()), (header, body));
+	assert!(Header::scan(DATA).is_none());
+	
+	let mut buf = [0; 8192];
+	let len = Header::read_with(Cursor::new(DATA), &mut buf, &config).unwrap();
+	assert_eq!(Some(HEADER_LEN), len);
+	
+	let (header, body) = Header::read_chunked_with(Cursor::new(DATA), 8192, &config).unwrap().unwrap();
+	assert_eq!((DATA[..HEADER_LEN].to_vec(), b"Body".to_vec()), (header, body));
+	assert!(Header::read_chunked(Cursor::new(DATA), 8192).unwrap().is_none());
+}
+
+
+#[test]
+fn test_parser() {
+	const DATA: &[u8] = b"GET / HTTP/1.1\r\nHost: example.com\n\nBody";
+	const HEADER_LEN: usize = DATA.len() - b"Body".len();
+	
+	let mut parser = HeaderParser::with_config(ParserConfig::lenient());
+	let mut position = 0;
+	for chunk in DATA.chunks(1) {
+		match parser.push(chunk).unwrap() {
+			ParseStatus::Partial => position += chunk.len(),
+			ParseStatus::Complete(header, consumed_len) => {
+				assert_eq!(HEADER_LEN, position + consumed_len);
+				let header: RequestHeader = header.try_into().unwrap();
+				assert_eq!("example.com", header.field(&"Host".try_into().unwrap()).unwrap());
+				return
+			}
+		}
+	}
+	panic!("Parser did not complete")
+}
@@ -0,0 +1,85 @@
+use http_header::{ HttpError, Header, HeaderParser, ParseLimits, ParserConfig };
+
+
+const DATA: &[u8] = concat!(
+	"GET /index.html?query=value HTTP/1.1\r\n",
+	"Host: www.heise.de\r\n",
+	"User-Agent: http_header/0.3.0\r\n",
+	"Accept: */*\r\n",
+	"\r\n"
+).as_bytes();
+
+
+/// Creates a parser config with `limits`
+fn config(limits: ParseLimits) -> ParserConfig {
+	ParserConfig{ limits, ..Default::default() }
+}
+
+
+struct Test {
+	limits: ParseLimits,
+	e: Option<HttpError>
+}
+impl Test {
+	fn test(self) {
+		let result = Header::parse_with(DATA, &config(self.limits));
+		assert_eq!(self.e, result.err().map(|e| e.kind));
+	}
+}
+#[test]
+fn test() {
+	Test{ limits: ParseLimits::default(), e: None }.test();
+	Test{ limits: ParseLimits::unlimited(), e: None }.test();
+	
+	Test{ limits: ParseLimits{ max_fields: 3, ..ParseLimits::default() }, e: None }.test();
+	Test {
+		limits: ParseLimits{ max_fields: 2, ..ParseLimits::default() },
+		e: Some(HttpError::TooManyFields)
+	}.test();
+	
+	Test {
+		limits: ParseLimits{ max_field_line_len: 29, ..ParseLimits::default() },
+		e: None
+	}.test();
+	Test {
+		limits: ParseLimits{ max_field_line_len: 28, ..ParseLimits::default() },
+		e: Some(HttpError::FieldLineTooLong)
+	}.test();
+	
+	Test{ limits: ParseLimits{ max_uri_len: 23, ..ParseLimits::default() }, e: None }.test();
+	Test {
+		limits: ParseLimits{ max_uri_len: 22, ..ParseLimits::default() },
+		e: Some(HttpError::UriTooLong)
+	}.test();
+	
+	Test {
+		limits: ParseLimits{ max_header_len: DATA.len(), ..ParseLimits::default() },
+		e: None
+	}.test();
+	Test {
+		limits: ParseLimits{ max_header_len: DATA.len() - 1, ..ParseLimits::default() },
+		e: Some(HttpError::HeaderTooLarge)
+	}.test();
+}
+
+
+#[test]
+fn test_truncated() {
+	let limits = ParseLimits{ max_header_len: 16, ..ParseLimits::default() };
+	let result = Header::parse_with(b"GET / HTTP/1.1\r\n", &config(limits));
+	assert_eq!(HttpError::TruncatedData, result.unwrap_err().kind);
+	
+	let result = Header::parse_with(b"GET /index.html HTTP/1.1\r\n", &config(limits));
+	assert_eq!(HttpError::HeaderTooLarge, result.unwrap_err().kind);
+}
+
+
+#[test]
+fn test_incremental() {
+	let limits = ParseLimits{ max_header_len: 32, ..ParseLimits::default() };
+	let mut parser = HeaderParser::with_config(config(limits));
+	
+	assert!(parser.push(&DATA[..32]).is_ok());
+	assert_eq!(HttpError::HeaderTooLarge, parser.push(&DATA[32..33]).unwrap_err().kind);
+	assert_eq!(0, parser.buffered());
+}
\ No newline at end of file
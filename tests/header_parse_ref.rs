@@ -0,0 +1,129 @@
+use http_header::{
+	HttpError, Header, HeaderRef, RequestHeader, RequestHeaderRef, ResponseHeader,
+	ResponseHeaderRef,
+	data::encodings::{ Encoding, HeaderFieldKey }
+};
+use std::convert::TryInto;
+
+
+struct TestRequest {
+	data: &'static[u8],
+	method: &'static[u8],
+	uri: &'static[u8],
+	version: &'static[u8],
+	fields: &'static[(&'static[u8], &'static[u8])]
+}
+impl TestRequest {
+	fn test(self) {
+		let header: RequestHeaderRef = HeaderRef::parse(self.data).unwrap().try_into().unwrap();
+		
+		assert_eq!(self.method, header.method());
+		assert_eq!(self.uri, header.uri());
+		assert_eq!(self.version, header.version());
+		let fields: Vec<_> = header.fields().iter().map(|(k, v)| (*k, v.as_ref())).collect();
+		assert_eq!(self.fields, fields.as_slice());
+		
+		// Compare the owned copy with an owned parse
+		let owned: RequestHeader = Header::parse(self.data).unwrap().try_into().unwrap();
+		assert_eq!(owned.to_vec(), header.to_owned().to_vec());
+	}
+}
+#[test]
+fn test_request() {
+	TestRequest {
+		data: b"HEAD / HTTP/1.1\r\n\r\n",
+		method: b"HEAD", uri: b"/", version: b"HTTP/1.1",
+		fields: &[]
+	}.test();
+	
+	TestRequest {
+		data: concat!(
+			"POST /upl%C3%B6ad/form.php HTTP/1.1\r\n",
+			"Host: www.heise.de\r\n",
+			"User-Agent: http_header/0.3.0\r\n",
+			"\r\n",
+			"Test\r\nBODY\r\nolope"
+		).as_bytes(),
+		method: b"POST", uri: b"/upl%C3%B6ad/form.php", version: b"HTTP/1.1",
+		fields: &[(b"Host", b"www.heise.de"), (b"User-Agent", b"http_header/0.3.0")]
+	}.test();
+}
+
+
+struct TestResponse {
+	data: &'static[u8],
+	version: &'static[u8],
+	status: u16,
+	reason: &'static[u8],
+	fields: &'static[(&'static[u8], &'static[u8])]
+}
+impl TestResponse {
+	fn test(self) {
+		let header: ResponseHeaderRef = HeaderRef::parse(self.data).unwrap().try_into().unwrap();
+		
+		assert_eq!(self.version, header.version());
+		assert_eq!(self.status, header.status());
+		assert_eq!(self.reason, header.reason());
+		let fields: Vec<_> = header.fields().iter().map(|(k, v)| (*k, v.as_ref())).collect();
+		assert_eq!(self.fields, fields.as_slice());
+		
+		// Compare the owned copy with an owned parse
+		let owned: ResponseHeader = Header::parse(self.data).unwrap().try_into().unwrap();
+		assert_eq!(owned.to_vec(), header.to_owned().to_vec());
+	}
+}
+#[test]
+fn test_response() {
+	TestResponse {
+		data: concat!(
+			"HTTP/1.1 404 Not Found\r\n",
+			"Server: nginx\r\n",
+			"Set-Cookie: a=1\r\n",
+			"Set-Cookie: b=2\r\n",
+			"\r\n"
+		).as_bytes(),
+		version: b"HTTP/1.1", status: 404, reason: b"Not Found",
+		fields: &[(b"Server", b"nginx"), (b"Set-Cookie", b"a=1"), (b"Set-Cookie", b"b=2")]
+	}.test();
+}
+
+
+#[test]
+fn test_field() {
+	let header = HeaderRef::parse(b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n").unwrap();
+	assert_eq!(Some(b"example.com".as_ref()), header.field("host"));
+	assert_eq!(Some(b"example.com".as_ref()), header.field(b"HOST"));
+	assert_eq!(None, header.field("Server"));
+	
+	// Keys that are not UTF-8 must not panic
+	assert_eq!(None, header.field(b"\xFF"));
+	assert_eq!(None, header.field(b"H\xC3\xB6st"));
+	assert!(!HeaderFieldKey::is_eq(b"\xFF", b"\xDF"));
+	assert!(HeaderFieldKey::is_eq(b"\xFFHost", b"\xFFhOST"));
+}
+
+
+struct TestErr {
+	data: &'static[u8],
+	e: HttpError
+}
+impl TestErr {
+	fn test(self) {
+		fn catch(data: &'static[u8]) -> Result<(), HttpError> {
+			let _: RequestHeaderRef = HeaderRef::parse(data)?.try_into()?;
+			Ok(())
+		}
+		assert_eq!(self.e, catch(self.data).unwrap_err())
+	}
+}
+#[test]
+fn test_err() {
+	TestErr{ data: b"HEAD / HTTP/1.1\r\n", e: HttpError::TruncatedData }.test();
+	TestErr{ data: b"HEAD / \r\n\r\n", e: HttpError::ProtocolViolation }.test();
+	TestErr{ data: b"HEAD /l\xC3\xB6l HTTP/1.1\r\n\r\n", e: HttpError::InvalidEncoding }.test();
+	TestErr{ data: b"HEAD /<> HTTP/1.1\r\n\r\n", e: HttpError::InvalidEncoding }.test();
+	TestErr {
+		data: b"HEAD / HTTP/1.1\r\nUser Agent: x\r\n\r\n",
+		e: HttpError::InvalidEncoding
+	}.test();
+}
\ No newline at end of file
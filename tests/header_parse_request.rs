@@ -1,21 +1,15 @@
 #[macro_use] extern crate http_header;
-use http_header::{
-	HttpError, Header, RequestHeader,
-	data::{
-		Data,
-		encodings::{ Ascii, HeaderFieldKey }
-	}
-};
-use std::{ collections::HashMap, convert::TryInto };
+use http_header::{ HttpError, Header, HeaderFields, RequestHeader };
+use std::convert::TryInto;
 
 
 macro_rules! map {
 	($($key:expr => $value:expr),+) => ({
-		let mut map = ::std::collections::HashMap::new();
-		$( map.insert(data!($key), data!($value)); )*
+		let mut map = ::http_header::HeaderFields::new();
+		$( map.append(data!($key), data!($value)); )*
 		map
 	});
-	() => (::std::collections::HashMap::new());
+	() => (::http_header::HeaderFields::new());
 }
 
 
@@ -24,19 +18,22 @@ struct Test {
 	method: &'static str,
 	uri: &'static str,
 	version: &'static str,
-	fields: HashMap<Data<HeaderFieldKey>, Data<Ascii>>,
+	fields: HeaderFields,
 	body: &'static[u8]
 }
 impl Test {
 	fn test(self) {
-		let (header, body) = Header::scan(self.data).unwrap();
-		let header: RequestHeader = Header::parse(header).unwrap().try_into().unwrap();
+		let (header_bytes, body) = Header::scan(self.data).unwrap();
+		let header: RequestHeader = Header::parse(header_bytes).unwrap().try_into().unwrap();
 		
 		assert_eq!(self.method, header.method());
 		assert_eq!(self.uri, header.uri());
 		assert_eq!(self.version, header.version());
 		assert_eq!(&self.fields, header.fields());
 		assert_eq!(self.body, body);
+		
+		// Ensure that a parse-write roundtrip preserves the header
+		assert_eq!(header_bytes, header.to_vec().as_slice());
 	}
 }
 #[test]
@@ -105,4 +102,12 @@ fn test_err() {
 			"\r\n"
 		).as_bytes(), e: HttpError::InvalidEncoding
 	}.test();
+	
+	TestErr {
+		data: concat!(
+			"HEAD / HTTP/1.1\r\n",
+			"Host: www.heise.de\nX-Injected: true\r\n",
+			"\r\n"
+		).as_bytes(), e: HttpError::InvalidEncoding
+	}.test();
 }
\ No newline at end of file
@@ -1,21 +1,15 @@
 #[macro_use] extern crate http_header;
-use http_header::{
-	HttpError, Header, ResponseHeader,
-	data::{
-		Data,
-		encodings::{ Ascii, HeaderFieldKey }
-	}
-};
-use std::{ collections::HashMap, convert::TryInto };
+use http_header::{ HttpError, Header, HeaderFields, ResponseHeader };
+use std::convert::TryInto;
 
 
 macro_rules! map {
 	($($key:expr => $value:expr),+) => ({
-		let mut map = ::std::collections::HashMap::new();
-		$( map.insert(data!($key), data!($value)); )*
+		let mut map = ::http_header::HeaderFields::new();
+		$( map.append(data!($key), data!($value)); )*
 		map
 	});
-	() => (::std::collections::HashMap::new());
+	() => (::http_header::HeaderFields::new());
 }
 
 
@@ -24,19 +18,22 @@ struct Test {
 	version: &'static str,
 	status: u16,
 	reason: &'static str,
-	fields: HashMap<Data<HeaderFieldKey>, Data<Ascii>>,
+	fields: HeaderFields,
 	body: &'static[u8]
 }
 impl Test {
 	fn test(self) {
-		let (header, body) = Header::scan(self.data).unwrap();
-		let header: ResponseHeader = Header::parse(header).unwrap().try_into().unwrap();
+		let (header_bytes, body) = Header::scan(self.data).unwrap();
+		let header: ResponseHeader = Header::parse(header_bytes).unwrap().try_into().unwrap();
 		
 		assert_eq!(self.version, header.version());
 		assert_eq!(self.status, header.status());
 		assert_eq!(self.reason, header.reason());
 		assert_eq!(&self.fields, header.fields());
 		assert_eq!(self.body, body);
+		
+		// Ensure that a parse-write roundtrip preserves the header
+		assert_eq!(header_bytes, header.to_vec().as_slice());
 	}
 }
 #[test]
@@ -87,6 +84,25 @@ fn test() {
 		),
 		body: b"Test\r\nBODY\r\nolope"
 	}.test();
+	
+	Test {
+		data: concat!(
+			"HTTP/1.1 200 OK\r\n",
+			"Set-Cookie: id=a3fWa; Max-Age=2592000\r\n",
+			"Via: 1.0 fred\r\n",
+			"set-cookie: lang=de\r\n",
+			"Via: 1.1 p.example.net\r\n",
+			"\r\n"
+		).as_bytes(),
+		version: "HTTP/1.1", status: 200, reason: "OK",
+		fields: map!(
+			"Set-Cookie" => "id=a3fWa; Max-Age=2592000",
+			"Via" => "1.0 fred",
+			"Set-Cookie" => "lang=de",
+			"Via" => "1.1 p.example.net"
+		),
+		body: b""
+	}.test();
 }
 
 
@@ -110,7 +126,8 @@ fn test_err() {
 	TestErr{ data: b"HTTP/1.1 200 \r\n\r\n", e: HttpError::ProtocolViolation }.test();
 	TestErr{ data: b"HTT\xC3\x9F/1.1 200 OK\r\n\r\n", e: HttpError::InvalidEncoding }.test();
 	TestErr{ data: b"HTTP/1.1 20O OK\r\n\r\n", e: HttpError::InvalidEncoding }.test();
-	TestErr{ data: b"HTTP/1.1 200 \xC3\x96K\r\n\r\n", e: HttpError::InvalidEncoding }.test();
+	TestErr{ data: b"HTTP/1.1 200 \x7FK\r\n\r\n", e: HttpError::InvalidEncoding }.test();
+	TestErr{ data: b"HTTP/1.1 200 OK\rX: y\r\n\r\n", e: HttpError::InvalidEncoding }.test();
 	
 	TestErr {
 		data: concat!(
@@ -130,4 +147,16 @@ fn test_err() {
 			"\r\n"
 		).as_bytes(), e: HttpError::InvalidEncoding
 	}.test();
+}
+
+
+#[test]
+fn test_obs_text() {
+	// Legacy ISO-8859-1 reason phrases and field values are accepted as `obs-text`
+	let data = b"HTTP/1.1 200 Gr\xFC\xDFe\r\nX-Name: M\xFCller\r\n\r\n";
+	let header: ResponseHeader = Header::parse(data).unwrap().try_into().unwrap();
+	assert_eq!(b"Gr\xFC\xDFe".as_ref(), header.reason() as &[u8]);
+	assert_eq!("Grüße", header.reason().to_string());
+	assert_eq!("Müller", header.field(&data!("X-Name")).unwrap().to_string());
+	assert_eq!(data.as_ref(), header.to_vec().as_slice());
 }
\ No newline at end of file
@@ -0,0 +1,69 @@
+use http_header::{ HttpError, HeaderParser, ParseStatus, RequestHeader };
+use std::convert::TryInto;
+
+
+const DATA: &[u8] = concat!(
+	"POST /upl%C3%B6ad/form.php HTTP/1.1\r\n",
+	"Host: www.heise.de\r\n",
+	"User-Agent: http_header/0.3.0\r\n",
+	"\r\n",
+	"Test\r\nBODY\r\nolope"
+).as_bytes();
+const HEADER_LEN: usize = DATA.len() - b"Test\r\nBODY\r\nolope".len();
+
+
+struct Test {
+	chunk_size: usize
+}
+impl Test {
+	pub fn test(self) {
+		let mut parser = HeaderParser::new();
+		let mut position = 0;
+		for chunk in DATA.chunks(self.chunk_size) {
+			match parser.push(chunk).unwrap() {
+				ParseStatus::Partial => position += chunk.len(),
+				ParseStatus::Complete(header, consumed_len) => {
+					assert_eq!(HEADER_LEN, position + consumed_len);
+					assert_eq!(0, parser.buffered());
+					
+					let header: RequestHeader = header.try_into().unwrap();
+					assert_eq!("POST", header.method());
+					assert_eq!("www.heise.de", header.field(&"Host".try_into().unwrap()).unwrap());
+					return
+				}
+			}
+		}
+		panic!("Parser did not complete")
+	}
+}
+#[test]
+fn test() {
+	for chunk_size in 1 ..= DATA.len() {
+		Test{ chunk_size }.test();
+	}
+}
+
+
+#[test]
+fn test_partial() {
+	let mut parser = HeaderParser::new();
+	assert!(matches!(parser.push(b"HEAD / HTTP/1.1\r\n").unwrap(), ParseStatus::Partial));
+	assert!(matches!(parser.push(b"Host: example.com\r\n\r").unwrap(), ParseStatus::Partial));
+	assert_eq!(b"HEAD / HTTP/1.1\r\nHost: example.com\r\n\r".len(), parser.buffered());
+	
+	parser.reset();
+	assert_eq!(0, parser.buffered());
+	assert!(matches!(parser.push(b"\n").unwrap(), ParseStatus::Partial));
+}
+
+
+#[test]
+fn test_err() {
+	let mut parser = HeaderParser::new();
+	assert!(matches!(parser.push(b"HEAD / \r\n").unwrap(), ParseStatus::Partial));
+	assert_eq!(HttpError::ProtocolViolation, parser.push(b"\r\n").unwrap_err().kind);
+	
+	// Ensure that the parser has been reset
+	let status = parser.push(b"HEAD / HTTP/1.1\r\n\r\n").unwrap();
+	assert!(matches!(status, ParseStatus::Complete(_, 19)));
+}
\ No newline at end of file
@@ -1,5 +1,5 @@
 use http_header::Header;
-use std::io::Cursor;
+use std::io::{ self, Read, Cursor };
 
 
 struct Test {
@@ -31,4 +31,80 @@ fn test() {
 		data: b"POST /upl%C3%B6ad/form.php HTTP/1.1",
 		len: None
 	}.test();
+}
+
+
+/// A reader that counts the `read`-calls
+struct CountingReader {
+	source: Cursor<&'static[u8]>,
+	calls: usize
+}
+impl Read for CountingReader {
+	fn read(&mut self, buf: &mut[u8]) -> Result<usize, io::Error> {
+		self.calls += 1;
+		self.source.read(buf)
+	}
+}
+
+
+struct TestChunked {
+	data: &'static[u8],
+	limit: usize,
+	header_body: Option<(&'static[u8], &'static[u8])>
+}
+impl TestChunked {
+	pub fn test(self) {
+		let mut source = CountingReader{ source: Cursor::new(self.data), calls: 0 };
+		let header_body = Header::read_chunked(&mut source, self.limit).unwrap();
+		
+		let header_body = header_body.as_ref().map(|(h, b)| (h.as_slice(), b.as_slice()));
+		assert_eq!(self.header_body, header_body);
+		assert!(source.calls <= 2);
+	}
+}
+#[test]
+fn test_chunked() {
+	TestChunked {
+		data: b"POST /upl%C3%B6ad/form.php HTTP/1.1\r\nHost: www.heise.de\r\n\r\nSome body data",
+		limit: 8192,
+		header_body: Some((
+			b"POST /upl%C3%B6ad/form.php HTTP/1.1\r\nHost: www.heise.de\r\n\r\n",
+			b"Some body data"
+		))
+	}.test();
+	
+	TestChunked {
+		data: b"POST /upl%C3%B6ad/form.php HTTP/1.1\r\n\r\n",
+		limit: 8192,
+		header_body: Some((b"POST /upl%C3%B6ad/form.php HTTP/1.1\r\n\r\n", b""))
+	}.test();
+	
+	TestChunked {
+		data: b"POST /upl%C3%B6ad/form.php HTTP/1.1\r\n\r\nSome body data",
+		limit: 16,
+		header_body: None
+	}.test();
+	
+	TestChunked {
+		data: b"POST /upl%C3%B6ad/form.php HTTP/1.1",
+		limit: 8192,
+		header_body: None
+	}.test();
+}
+
+
+/// A reader that returns at most three bytes per `read`-call
+struct SlowReader(Cursor<&'static[u8]>);
+impl Read for SlowReader {
+	fn read(&mut self, buf: &mut[u8]) -> Result<usize, io::Error> {
+		let len = buf.len().min(3);
+		self.0.read(&mut buf[..len])
+	}
+}
+#[test]
+fn test_chunked_split() {
+	let source = SlowReader(Cursor::new(b"GET / HTTP/1.1\r\nHost: www.heise.de\r\n\r\nBody"));
+	let (header, body) = Header::read_chunked(source, 8192).unwrap().unwrap();
+	assert_eq!(b"GET / HTTP/1.1\r\nHost: www.heise.de\r\n\r\n".as_ref(), header.as_slice());
+	assert_eq!(b"B".as_ref(), body.as_slice());
 }
\ No newline at end of file
@@ -0,0 +1,104 @@
+use http_header::{
+	HttpError, Header, HeaderRef, ResponseBuilder, ResponseHeader, ResponseHeaderRef, StatusCode,
+	data
+};
+use std::convert::{ TryFrom, TryInto };
+
+
+struct Test {
+	code: u16,
+	reason: Option<&'static str>,
+	class: [bool; 5]
+}
+impl Test {
+	pub fn test(self) {
+		let code = StatusCode::try_from(self.code).unwrap();
+		assert_eq!(self.code, code.as_u16());
+		assert_eq!(self.code.to_string(), code.to_string());
+		assert_eq!(code, StatusCode::try_from(self.code.to_string().as_str()).unwrap());
+		assert_eq!(self.reason, code.canonical_reason());
+		
+		let class = [
+			code.is_informational(), code.is_success(), code.is_redirection(),
+			code.is_client_error(), code.is_server_error()
+		];
+		assert_eq!(self.class, class);
+	}
+}
+#[test]
+fn test() {
+	Test{ code: 100, reason: Some("Continue"), class: [true, false, false, false, false] }.test();
+	Test{ code: 200, reason: Some("OK"), class: [false, true, false, false, false] }.test();
+	Test{ code: 299, reason: None, class: [false, true, false, false, false] }.test();
+	Test {
+		code: 308, reason: Some("Permanent Redirect"),
+		class: [false, false, true, false, false]
+	}.test();
+	Test{ code: 404, reason: Some("Not Found"), class: [false, false, false, true, false] }.test();
+	Test{ code: 599, reason: None, class: [false, false, false, false, true] }.test();
+	
+	assert_eq!(StatusCode::NOT_FOUND, 404);
+	assert_eq!(
+		Some("Request Header Fields Too Large"),
+		StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE.canonical_reason()
+	);
+}
+
+
+#[test]
+fn test_err() {
+	assert_eq!(HttpError::ProtocolViolation, StatusCode::try_from(0).unwrap_err());
+	assert_eq!(HttpError::ProtocolViolation, StatusCode::try_from(99).unwrap_err());
+	assert_eq!(HttpError::ProtocolViolation, StatusCode::try_from(600).unwrap_err());
+	assert_eq!(HttpError::ProtocolViolation, StatusCode::try_from("99999").unwrap_err());
+	assert_eq!(HttpError::ProtocolViolation, StatusCode::try_from("0200").unwrap_err());
+	assert_eq!(HttpError::ProtocolViolation, StatusCode::try_from("").unwrap_err());
+	assert_eq!(HttpError::InvalidEncoding, StatusCode::try_from("2O0").unwrap_err());
+}
+
+
+#[test]
+fn test_header() {
+	const DATA: &[u8] = b"HTTP/1.1 404 Not Found\r\n\r\n";
+	let header: ResponseHeader = Header::parse(DATA).unwrap().try_into().unwrap();
+	assert_eq!(StatusCode::NOT_FOUND, header.status());
+	
+	let header: ResponseHeaderRef = HeaderRef::parse(DATA).unwrap().try_into().unwrap();
+	assert!(header.status().is_client_error());
+	
+	for data in [b"HTTP/1.1 99999 Foo\r\n\r\n".as_ref(), b"HTTP/1.1 000 Foo\r\n\r\n"] {
+		let header = Header::parse(data).unwrap();
+		assert_eq!(HttpError::ProtocolViolation, ResponseHeader::try_from(header).unwrap_err());
+		
+		let header = HeaderRef::parse(data).unwrap();
+		assert_eq!(HttpError::ProtocolViolation, ResponseHeaderRef::try_from(header).unwrap_err());
+	}
+}
+
+
+#[test]
+fn test_builder() {
+	let header = ResponseBuilder::new()
+		.version(data!("HTTP/1.1"))
+		.status_code(StatusCode::NOT_FOUND)
+		.build().unwrap();
+	assert_eq!(b"HTTP/1.1 404 Not Found\r\n\r\n".as_ref(), header.to_vec().as_slice());
+	
+	let header = ResponseBuilder::new()
+		.version(data!("HTTP/1.1"))
+		.reason(data!("Nope"))
+		.status_code(StatusCode::NOT_FOUND)
+		.build().unwrap();
+	assert_eq!(b"HTTP/1.1 404 Nope\r\n\r\n".as_ref(), header.to_vec().as_slice());
+	
+	let builder = ResponseBuilder::new()
+		.version(data!("HTTP/1.1"))
+		.status_code(StatusCode::try_from(299).unwrap());
+	assert_eq!(HttpError::ApiMisuse, builder.build().unwrap_err());
+	
+	let builder = ResponseBuilder::new()
+		.version(data!("HTTP/1.1"))
+		.status(1000)
+		.reason(data!("Nope"));
+	assert_eq!(HttpError::ProtocolViolation, builder.build().unwrap_err());
+}
@@ -0,0 +1,127 @@
+use http_header::{
+	HttpError, Header, HeaderRef, Method, RequestBuilder, RequestHeader, RequestHeaderRef,
+	RequestTarget, data
+};
+use std::convert::{ TryFrom, TryInto };
+
+
+struct Test {
+	method: Method,
+	target: &'static str,
+	expected: RequestTarget
+}
+impl Test {
+	pub fn test(self) {
+		let target = RequestTarget::parse(&self.method, self.target.as_bytes()).unwrap();
+		assert_eq!(self.expected, target);
+		assert_eq!(self.target, target.as_str());
+	}
+}
+#[test]
+fn test() {
+	Test {
+		method: Method::Get, target: "/where?q=now",
+		expected: RequestTarget::Origin(data!("/where?q=now"))
+	}.test();
+	Test {
+		method: Method::Post, target: "/",
+		expected: RequestTarget::Origin(data!("/"))
+	}.test();
+	Test {
+		method: Method::Get, target: "http://www.example.org/pub/WWW/TheProject.html",
+		expected: RequestTarget::Absolute(data!("http://www.example.org/pub/WWW/TheProject.html"))
+	}.test();
+	Test {
+		method: Method::Options, target: "http://[::1]:8080",
+		expected: RequestTarget::Absolute(data!("http://[::1]:8080"))
+	}.test();
+	Test {
+		method: Method::Connect, target: "www.example.com:443",
+		expected: RequestTarget::Authority(data!("www.example.com:443"))
+	}.test();
+	Test {
+		method: Method::Connect, target: "[::1]:443",
+		expected: RequestTarget::Authority(data!("[::1]:443"))
+	}.test();
+	Test {
+		method: Method::Options, target: "*",
+		expected: RequestTarget::Asterisk
+	}.test();
+	Test {
+		method: Method::Options, target: "/index.html",
+		expected: RequestTarget::Origin(data!("/index.html"))
+	}.test();
+}
+
+
+struct TestErr {
+	method: Method,
+	target: &'static str,
+	e: HttpError
+}
+impl TestErr {
+	pub fn test(self) {
+		let e = RequestTarget::parse(&self.method, self.target.as_bytes()).unwrap_err();
+		assert_eq!(self.e, e, "{}", self.target);
+	}
+}
+#[test]
+fn test_err() {
+	TestErr{ method: Method::Get, target: "*", e: HttpError::ProtocolViolation }.test();
+	TestErr {
+		method: Method::Get, target: "/index.html#top",
+		e: HttpError::ProtocolViolation
+	}.test();
+	TestErr {
+		method: Method::Get, target: "http://host/#top",
+		e: HttpError::ProtocolViolation
+	}.test();
+	TestErr{ method: Method::Get, target: "index.html", e: HttpError::ProtocolViolation }.test();
+	TestErr{ method: Method::Get, target: "1http://host/", e: HttpError::ProtocolViolation }.test();
+	TestErr{ method: Method::Get, target: "/<>", e: HttpError::InvalidEncoding }.test();
+	TestErr {
+		method: Method::Connect, target: "/index.html",
+		e: HttpError::ProtocolViolation
+	}.test();
+	TestErr {
+		method: Method::Connect, target: "example.com",
+		e: HttpError::ProtocolViolation
+	}.test();
+	TestErr {
+		method: Method::Connect, target: "example.com:",
+		e: HttpError::ProtocolViolation
+	}.test();
+	TestErr {
+		method: Method::Connect, target: "user@host:443",
+		e: HttpError::ProtocolViolation
+	}.test();
+	TestErr{ method: Method::Connect, target: "*", e: HttpError::ProtocolViolation }.test();
+}
+
+
+#[test]
+fn test_header() {
+	const DATA: &[u8] = b"CONNECT www.example.com:443 HTTP/1.1\r\nHost: www.example.com:443\r\n\r\n";
+	let header: RequestHeader = Header::parse(DATA).unwrap().try_into().unwrap();
+	assert_eq!(&RequestTarget::Authority(data!("www.example.com:443")), header.target());
+	
+	const INVALID: &[u8] = b"GET * HTTP/1.1\r\n\r\n";
+	let header = Header::parse(INVALID).unwrap();
+	assert_eq!(HttpError::ProtocolViolation, RequestHeader::try_from(header).unwrap_err());
+	
+	let header = HeaderRef::parse(INVALID).unwrap();
+	assert_eq!(HttpError::ProtocolViolation, RequestHeaderRef::try_from(header).unwrap_err());
+	
+	let builder = RequestBuilder::new()
+		.method(Method::Connect)
+		.uri(data!("/index.html"))
+		.version(data!("HTTP/1.1"));
+	assert_eq!(HttpError::ProtocolViolation, builder.build().unwrap_err());
+	
+	let header = RequestBuilder::new()
+		.method(Method::Options)
+		.uri(data!("*"))
+		.version(data!("HTTP/1.1"))
+		.build().unwrap();
+	assert_eq!(&RequestTarget::Asterisk, header.target());
+}
@@ -0,0 +1,109 @@
+use http_header::{
+	Component, HttpError, Header, ParserConfig, RequestHeader, ResponseBuilder, ResponseHeader,
+	Version, data
+};
+use std::convert::{ TryFrom, TryInto };
+
+
+struct Test {
+	version: &'static str,
+	expected: Version,
+	major: u8,
+	minor: u8,
+	is_keep_alive_default: bool,
+	requires_host: bool
+}
+impl Test {
+	pub fn test(self) {
+		let version = Version::try_from(self.version).unwrap();
+		assert_eq!(self.expected, version);
+		assert_eq!(self.version, version.as_str());
+		assert_eq!(self.version, version.to_string());
+		assert_eq!((self.major, self.minor), (version.major(), version.minor()));
+		assert_eq!(self.is_keep_alive_default, version.is_keep_alive_default());
+		assert_eq!(self.requires_host, version.requires_host());
+	}
+}
+#[test]
+fn test() {
+	Test {
+		version: "HTTP/0.9", expected: Version::Http09, major: 0, minor: 9,
+		is_keep_alive_default: false, requires_host: false
+	}.test();
+	Test {
+		version: "HTTP/1.0", expected: Version::Http10, major: 1, minor: 0,
+		is_keep_alive_default: false, requires_host: false
+	}.test();
+	Test {
+		version: "HTTP/1.1", expected: Version::Http11, major: 1, minor: 1,
+		is_keep_alive_default: true, requires_host: true
+	}.test();
+	
+	assert!(Version::Http09 < Version::Http10);
+	assert!(Version::Http10 < Version::Http11);
+}
+
+
+#[test]
+fn test_err() {
+	assert_eq!(HttpError::UnsupportedVersion, Version::try_from("HTTP/2.0").unwrap_err());
+	assert_eq!(HttpError::UnsupportedVersion, Version::try_from("HTTP/1.2").unwrap_err());
+	assert_eq!(HttpError::ProtocolViolation, Version::try_from("HTTP/2").unwrap_err());
+	assert_eq!(HttpError::ProtocolViolation, Version::try_from("http/1.1").unwrap_err());
+	assert_eq!(HttpError::ProtocolViolation, Version::try_from("HTTP/1.10").unwrap_err());
+	assert_eq!(HttpError::ProtocolViolation, Version::try_from("").unwrap_err());
+}
+
+
+struct TestParseErr {
+	data: &'static[u8],
+	kind: HttpError,
+	offset: usize
+}
+impl TestParseErr {
+	pub fn test(self) {
+		let e = Header::parse_with(self.data, &ParserConfig::default()).unwrap_err();
+		assert_eq!(self.kind, e.kind, "{}", e);
+		assert_eq!(self.offset, e.offset, "{}", e);
+		assert_eq!(Component::Version, e.component, "{}", e);
+	}
+}
+#[test]
+fn test_parse_err() {
+	TestParseErr {
+		data: b"GET / HTTP/2.0\r\n\r\n",
+		kind: HttpError::UnsupportedVersion, offset: 6
+	}.test();
+	TestParseErr {
+		data: b"GET / FOO\r\n\r\n",
+		kind: HttpError::ProtocolViolation, offset: 6
+	}.test();
+	TestParseErr {
+		data: b"HTTP/3.0 200 OK\r\n\r\n",
+		kind: HttpError::UnsupportedVersion, offset: 0
+	}.test();
+	TestParseErr {
+		data: b"HTTP/2 200 OK\r\n\r\n",
+		kind: HttpError::ProtocolViolation, offset: 0
+	}.test();
+}
+
+
+#[test]
+fn test_header() {
+	let request: RequestHeader = Header::parse(b"GET / HTTP/1.0\r\n\r\n").unwrap().try_into().unwrap();
+	assert_eq!(&Version::Http10, request.version());
+	assert!(!request.version().is_keep_alive_default());
+	
+	let response: ResponseHeader = Header::parse(b"HTTP/1.1 200 OK\r\n\r\n").unwrap()
+		.try_into().unwrap();
+	assert_eq!(&Version::Http11, response.version());
+	
+	let built = ResponseBuilder::new()
+		.version(Version::Http10)
+		.status(200)
+		.reason(data!("OK"))
+		.build().unwrap();
+	assert_eq!(&Version::Http10, built.version());
+	assert_eq!(b"HTTP/1.0 200 OK\r\n\r\n".as_ref(), built.to_vec().as_slice());
+}
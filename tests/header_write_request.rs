@@ -38,6 +38,26 @@ fn test() {
 			"\r\n"
 		).as_bytes()
 	}.test();
+	
+	Test {
+		header: RequestBuilder::new()
+			.method(data!("GET"))
+			.uri(data!("/"))
+			.version(data!("HTTP/1.1"))
+			.field(data!("User-Agent"), data!("http_header"))
+			.field(data!("Host"), data!("www.heise.de"))
+			.field(data!("Accept"), data!("*/*"))
+			.field(data!("Connection"), data!("close"))
+			.build().unwrap(),
+		data: concat!(
+			"GET / HTTP/1.1\r\n",
+			"User-Agent: http_header\r\n",
+			"Host: www.heise.de\r\n",
+			"Accept: */*\r\n",
+			"Connection: close\r\n",
+			"\r\n"
+		).as_bytes()
+	}.test();
 }
 
 
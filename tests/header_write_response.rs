@@ -1,6 +1,9 @@
 #[macro_use] extern crate http_header;
-use http_header::{ HttpError, ResponseBuilder, ResponseHeader };
-use std::io::Cursor;
+use http_header::{
+	HttpError, ResponseBuilder, ResponseHeader,
+	data::{ Data, encodings::FieldValue }
+};
+use std::{ io::Cursor, convert::TryFrom };
 
 
 struct Test {
@@ -38,6 +41,23 @@ fn test() {
 			"\r\n"
 		).as_bytes()
 	}.test();
+	
+	Test {
+		header: ResponseBuilder::new()
+			.version(data!("HTTP/1.1"))
+			.status(200)
+			.reason(data!("OK"))
+			.field(data!("Set-Cookie"), data!("id=a3fWa"))
+			.field(data!("Set-Cookie"), data!("id=b4gXb"))
+			.append_field(data!("Set-Cookie"), data!("lang=de"))
+			.build().unwrap(),
+		data: concat!(
+			"HTTP/1.1 200 OK\r\n",
+			"Set-Cookie: id=b4gXb\r\n",
+			"Set-Cookie: lang=de\r\n",
+			"\r\n"
+		).as_bytes()
+	}.test();
 }
 
 
@@ -73,4 +93,17 @@ fn test_err() {
 			.reason(data!("OK")),
 		e: HttpError::ApiMisuse
 	}.test();
+}
+
+
+#[test]
+fn test_injection() {
+	let value = Data::<FieldValue>::try_from("x\r\nSet-Cookie: evil");
+	assert_eq!(HttpError::InvalidEncoding, value.unwrap_err());
+	
+	let reason = Data::<FieldValue>::try_from("OK\r\nSet-Cookie: evil");
+	assert_eq!(HttpError::InvalidEncoding, reason.unwrap_err());
+	
+	let value = Data::<FieldValue>::try_from("text/html; charset=UTF-8\t");
+	assert!(value.is_ok());
 }
\ No newline at end of file
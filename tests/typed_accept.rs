@@ -0,0 +1,131 @@
+use http_header::{
+	Header, HttpError, RequestHeader,
+	data::{ Data, encodings::FieldValue },
+	typed::{ self, Accept, MediaType, Quality, TypedHeader }
+};
+use std::convert::{ TryFrom, TryInto };
+
+
+/// Decodes an `Accept` header from `value`
+fn accept(value: &str) -> Accept {
+	let value: Data<FieldValue> = Data::try_from(value).unwrap();
+	Accept::decode(&[&value]).unwrap()
+}
+/// Parses all `media_types`
+fn media_types(media_types: &[&str]) -> Vec<MediaType> {
+	media_types.iter().map(|media_type| MediaType::try_from(*media_type).unwrap()).collect()
+}
+
+
+struct Test {
+	accept: &'static str,
+	available: &'static[&'static str],
+	expected: Option<&'static str>
+}
+impl Test {
+	pub fn test(self) {
+		let selected = typed::negotiate(&accept(self.accept), &media_types(self.available));
+		assert_eq!(self.expected, selected.map(|m| m.to_string()).as_deref(), "{}", self.accept);
+	}
+}
+#[test]
+fn test_negotiate() {
+	const AVAILABLE: &[&str] = &["application/json", "application/cbor", "text/html"];
+	
+	Test{ accept: "text/html", available: AVAILABLE, expected: Some("text/html") }.test();
+	Test{ accept: "*/*", available: AVAILABLE, expected: Some("application/json") }.test();
+	Test{ accept: "", available: AVAILABLE, expected: Some("application/json") }.test();
+	Test{ accept: "image/png", available: AVAILABLE, expected: None }.test();
+	Test {
+		accept: "application/cbor;q=0.9, application/json;q=0.8, */*;q=0.1",
+		available: AVAILABLE, expected: Some("application/cbor")
+	}.test();
+	Test {
+		accept: "text/html;q=0.5, application/*;q=0.5", available: AVAILABLE,
+		expected: Some("application/json")
+	}.test();
+	
+	// A more specific range overrides a less specific one, even with lower quality
+	Test {
+		accept: "application/*, application/json;q=0", available: AVAILABLE,
+		expected: Some("application/cbor")
+	}.test();
+	Test {
+		accept: "*/*;q=0.1, text/*;q=0.9, text/html;q=0", available: AVAILABLE,
+		expected: Some("application/json")
+	}.test();
+	Test{ accept: "*/*;q=0", available: AVAILABLE, expected: None }.test();
+	Test{ accept: "text/html", available: &[], expected: None }.test();
+}
+
+
+#[test]
+fn test_quality() {
+	// See https://tools.ietf.org/html/rfc7231#section-5.3.2
+	let accept = accept(
+		"text/*;q=0.3, text/html;q=0.7, text/html;level=1, text/html;level=2;q=0.4, */*;q=0.5"
+	);
+	let quality = |media_type: &str| {
+		accept.quality(&MediaType::try_from(media_type).unwrap()).as_thousandths()
+	};
+	assert_eq!(1000, quality("text/html;level=1"));
+	assert_eq!(700, quality("text/html"));
+	assert_eq!(300, quality("text/plain"));
+	assert_eq!(500, quality("image/jpeg"));
+	assert_eq!(400, quality("text/html;level=2"));
+	assert_eq!(700, quality("text/html;level=3"));
+	
+	assert_eq!(Quality::ONE, Accept::default().quality(&MediaType::new("a", "b").unwrap()));
+}
+
+
+#[test]
+fn test_decode() {
+	let accept = accept("text/html;level=1;Q=0.5;ext=\"a b\";flag=x,, ,application/json ");
+	assert_eq!(2, accept.0.len());
+	
+	let range = &accept.0[0];
+	assert_eq!("text/html;level=1", range.value.media_type.to_string());
+	assert_eq!(500, range.quality.as_thousandths());
+	assert_eq!(2, range.value.extensions.len());
+	assert_eq!(
+		"text/html;level=1;q=0.5;ext=\"a b\";flag=x, application/json",
+		accept.encode().to_string()
+	);
+	
+	assert_eq!(Quality::ONE, accept.0[1].quality);
+	assert_eq!(accept, Accept::decode(&[&accept.encode()]).unwrap());
+	
+	let data = b"GET / HTTP/1.1\r\nAccept: text/*\r\nAccept: a/b;q=0.25\r\n\r\n";
+	let header: RequestHeader = Header::parse(data).unwrap().try_into().unwrap();
+	let accept = header.typed_get::<Accept>().unwrap().unwrap();
+	assert_eq!("text/*, a/b;q=0.25", accept.encode().to_string());
+}
+
+
+#[test]
+fn test_decode_err() {
+	let decode = |value: &str| {
+		let value: Data<FieldValue> = Data::try_from(value).unwrap();
+		Accept::decode(&[&value]).unwrap_err()
+	};
+	assert_eq!(HttpError::ProtocolViolation, decode("text"));
+	assert_eq!(HttpError::ProtocolViolation, decode("*/html"));
+	assert_eq!(HttpError::ProtocolViolation, decode("text/html;q=2"));
+	assert_eq!(HttpError::ProtocolViolation, decode("text/html;q=1.001"));
+	assert_eq!(HttpError::ProtocolViolation, decode("text/html;q=0.0001"));
+	assert_eq!(HttpError::ProtocolViolation, decode("text/html;q=.5"));
+	assert_eq!(HttpError::ProtocolViolation, decode("text/html text/plain"));
+}
+
+
+#[test]
+fn test_quality_value() {
+	assert_eq!(Ok(Quality::ONE), Quality::parse(b"1.000"));
+	assert_eq!(Ok(Quality::ZERO), Quality::parse(b"0."));
+	assert_eq!(5, Quality::parse(b"0.005").unwrap().as_thousandths());
+	assert_eq!("0.005", Quality::from_thousandths(5).unwrap().to_string());
+	assert_eq!("0.25", Quality::from_thousandths(250).unwrap().to_string());
+	assert_eq!("1", Quality::ONE.to_string());
+	assert_eq!(Err(HttpError::ApiMisuse), Quality::from_thousandths(1001));
+}
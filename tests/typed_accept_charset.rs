@@ -0,0 +1,53 @@
+use http_header::{
+	Header, HttpError, RequestHeader,
+	data::{ Data, encodings::{ FieldValue, HeaderFieldKey } },
+	typed::{ AcceptCharset, Quality, TypedHeader }
+};
+use std::convert::{ TryFrom, TryInto };
+
+
+/// Decodes an `Accept-Charset` header from `value`
+fn accept_charset(value: &str) -> AcceptCharset {
+	let value: Data<FieldValue> = Data::try_from(value).unwrap();
+	AcceptCharset::decode(&[&value]).unwrap()
+}
+
+
+#[test]
+fn test_negotiate() {
+	const AVAILABLE: &[&str] = &["utf-8", "ISO-8859-1"];
+	
+	assert_eq!(Some("utf-8"), accept_charset("UTF-8").negotiate(AVAILABLE));
+	assert_eq!(Some("ISO-8859-1"), accept_charset("iso-8859-1, utf-8;q=0.5").negotiate(AVAILABLE));
+	assert_eq!(Some("ISO-8859-1"), accept_charset("utf-8;q=0, *").negotiate(AVAILABLE));
+	assert_eq!(Some("utf-8"), accept_charset("*").negotiate(AVAILABLE));
+	assert_eq!(None, accept_charset("utf-16").negotiate(AVAILABLE));
+	assert_eq!(None, accept_charset("*;q=0").negotiate(AVAILABLE));
+	assert_eq!(Some("utf-8"), AcceptCharset::default().negotiate(AVAILABLE));
+	
+	let quality = accept_charset("utf-8, *;q=0.3").quality("windows-1252");
+	assert_eq!(300, quality.as_thousandths());
+	assert_eq!(Quality::ZERO, accept_charset("utf-8").quality("windows-1252"));
+}
+
+
+#[test]
+fn test_from_fields() {
+	let data = b"GET / HTTP/1.1\r\nAccept-Charset: utf-8;q=0.7, iso-8859-5\r\n\r\n";
+	let header: RequestHeader = Header::parse(data).unwrap().try_into().unwrap();
+	let key: Data<HeaderFieldKey> = Data::try_from("Accept-Charset").unwrap();
+	let accept_charset = AcceptCharset::from_fields(header.fields().get_all(&key)).unwrap();
+	assert_eq!(Some("iso-8859-5"), accept_charset.negotiate(&["utf-8", "iso-8859-5"]));
+	assert_eq!("utf-8;q=0.7, iso-8859-5", accept_charset.encode().to_string());
+	
+	// Repeated field lines form one list (see RFC 7230, section 3.2.2)
+	let data = b"GET / HTTP/1.1\r\nAccept-Charset: utf-8\r\nAccept-Charset: *;q=0\r\n\r\n";
+	let header: RequestHeader = Header::parse(data).unwrap().try_into().unwrap();
+	let accept_charset = AcceptCharset::from_fields(header.fields().get_all(&key)).unwrap();
+	assert_eq!(None, accept_charset.negotiate(&["iso-8859-1"]));
+	
+	assert_eq!(AcceptCharset::default(), AcceptCharset::from_fields(None).unwrap());
+	
+	let value: Data<FieldValue> = Data::try_from("utf-8;charset=x").unwrap();
+	assert_eq!(Err(HttpError::ProtocolViolation), AcceptCharset::from_fields(Some(&value)));
+}
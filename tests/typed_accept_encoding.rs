@@ -0,0 +1,105 @@
+use http_header::{
+	Header, HttpError, RequestHeader,
+	data::{ Data, encodings::{ FieldValue, HeaderFieldKey } },
+	typed::{ AcceptEncoding, Quality, TypedHeader }
+};
+use std::convert::{ TryFrom, TryInto };
+
+
+/// Decodes an `Accept-Encoding` header from `value`
+fn accept_encoding(value: &str) -> AcceptEncoding {
+	let value: Data<FieldValue> = Data::try_from(value).unwrap();
+	AcceptEncoding::decode(&[&value]).unwrap()
+}
+
+
+struct Test {
+	accept_encoding: &'static str,
+	available: &'static[&'static str],
+	expected: Option<&'static str>
+}
+impl Test {
+	pub fn test(self) {
+		let selected = accept_encoding(self.accept_encoding).negotiate(self.available);
+		assert_eq!(self.expected, selected, "{}", self.accept_encoding);
+	}
+}
+#[test]
+fn test_negotiate() {
+	const AVAILABLE: &[&str] = &["br", "gzip", "identity"];
+	
+	Test{ accept_encoding: "gzip", available: AVAILABLE, expected: Some("gzip") }.test();
+	Test{ accept_encoding: "GZIP, br", available: AVAILABLE, expected: Some("br") }.test();
+	Test {
+		accept_encoding: "gzip;q=1, br;q=0.5", available: AVAILABLE, expected: Some("gzip")
+	}.test();
+	Test{ accept_encoding: "*", available: AVAILABLE, expected: Some("br") }.test();
+	Test{ accept_encoding: "compress", available: AVAILABLE, expected: Some("identity") }.test();
+	Test{ accept_encoding: "compress", available: &["gzip"], expected: None }.test();
+	
+	// An empty value only accepts `identity`
+	Test{ accept_encoding: "", available: AVAILABLE, expected: Some("identity") }.test();
+	
+	// `q=0` excludes codings and `identity` explicitly or via `*`
+	Test{ accept_encoding: "br;q=0, *", available: AVAILABLE, expected: Some("gzip") }.test();
+	Test{ accept_encoding: "identity;q=0", available: &["identity"], expected: None }.test();
+	Test{ accept_encoding: "*;q=0", available: AVAILABLE, expected: None }.test();
+	Test {
+		accept_encoding: "*;q=0, identity;q=0.1", available: AVAILABLE,
+		expected: Some("identity")
+	}.test();
+	Test {
+		accept_encoding: "gzip;q=0.5, *;q=0.1, identity;q=0", available: &["identity", "br"],
+		expected: Some("br")
+	}.test();
+}
+
+
+#[test]
+fn test_from_fields() {
+	let data = b"GET / HTTP/1.1\r\nAccept-Encoding: deflate, gzip;q=0.8\r\n\r\n";
+	let header: RequestHeader = Header::parse(data).unwrap().try_into().unwrap();
+	let key: Data<HeaderFieldKey> = Data::try_from("accept-encoding").unwrap();
+	let accept_encoding = AcceptEncoding::from_fields(header.fields().get_all(&key)).unwrap();
+	assert_eq!(Some("gzip"), accept_encoding.negotiate(&["br", "gzip"]));
+	assert_eq!(800, accept_encoding.quality("gzip").as_thousandths());
+	
+	// Repeated field lines form one list (see RFC 7230, section 3.2.2)
+	let data = concat!(
+		"GET / HTTP/1.1\r\n",
+		"Accept-Encoding: gzip\r\n",
+		"Accept-Encoding: br;q=0, *;q=0.5\r\n",
+		"\r\n"
+	);
+	let header: RequestHeader = Header::parse(data.as_bytes()).unwrap().try_into().unwrap();
+	let accept_encoding = AcceptEncoding::from_fields(header.fields().get_all(&key)).unwrap();
+	assert_eq!(Quality::ZERO, accept_encoding.quality("br"));
+	assert_eq!(Some("deflate"), accept_encoding.negotiate(&["br", "deflate"]));
+	assert_eq!(accept_encoding, header.typed_get::<AcceptEncoding>().unwrap().unwrap());
+	
+	// A missing field accepts every coding
+	let accept_encoding = AcceptEncoding::from_fields(None).unwrap();
+	assert_eq!(Some("br"), accept_encoding.negotiate(&["br", "gzip"]));
+	assert_eq!(Quality::ONE, accept_encoding.quality("identity"));
+	
+	assert_eq!(Quality::ONE, AcceptEncoding::default().quality("identity"));
+	assert_eq!(Quality::ZERO, AcceptEncoding::default().quality("gzip"));
+}
+
+
+#[test]
+fn test_decode() {
+	let accept_encoding = accept_encoding(" gzip ; q=0.50,, *;Q=0 ,br");
+	assert_eq!(3, accept_encoding.0.len());
+	assert_eq!("gzip;q=0.5, *;q=0, br", accept_encoding.encode().to_string());
+	assert_eq!(accept_encoding, AcceptEncoding::decode(&[&accept_encoding.encode()]).unwrap());
+	
+	let decode = |value: &str| {
+		let value: Data<FieldValue> = Data::try_from(value).unwrap();
+		AcceptEncoding::decode(&[&value]).unwrap_err()
+	};
+	assert_eq!(HttpError::ProtocolViolation, decode("gzip;q=2"));
+	assert_eq!(HttpError::ProtocolViolation, decode("gzip;level=1"));
+	assert_eq!(HttpError::ProtocolViolation, decode("gzip br"));
+	assert_eq!(HttpError::ProtocolViolation, decode("gzip;q"));
+}
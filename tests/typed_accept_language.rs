@@ -0,0 +1,100 @@
+use http_header::{
+	Header, HttpError, RequestHeader,
+	data::{ Data, encodings::{ FieldValue, HeaderFieldKey } },
+	typed::{ AcceptLanguage, Quality, TypedHeader }
+};
+use std::convert::{ TryFrom, TryInto };
+
+
+/// Decodes an `Accept-Language` header from `value`
+fn accept_language(value: &str) -> AcceptLanguage {
+	let value: Data<FieldValue> = Data::try_from(value).unwrap();
+	AcceptLanguage::decode(&[&value]).unwrap()
+}
+
+
+#[test]
+fn test_negotiate() {
+	const AVAILABLE: &[&str] = &["en-US", "de-DE", "de-CH", "fr"];
+	
+	assert_eq!(Some("de-DE"), accept_language("de").negotiate(AVAILABLE));
+	assert_eq!(Some("de-CH"), accept_language("de-ch, de;q=0.8").negotiate(AVAILABLE));
+	assert_eq!(Some("fr"), accept_language("fr-CA, fr;q=0.9, en;q=0.8").negotiate(AVAILABLE));
+	assert_eq!(Some("en-US"), accept_language("*").negotiate(AVAILABLE));
+	assert_eq!(Some("fr"), accept_language("en;q=0, de;q=0, *;q=0.1").negotiate(AVAILABLE));
+	assert_eq!(None, accept_language("es, it").negotiate(AVAILABLE));
+	assert_eq!(Some("en-US"), AcceptLanguage::default().negotiate(AVAILABLE));
+	
+	// The most specific range wins
+	let accept_language = accept_language("de;q=0.5, de-de;q=0.9, *;q=0.1");
+	assert_eq!(900, accept_language.quality("de-DE-1996").as_thousandths());
+	assert_eq!(500, accept_language.quality("de-CH").as_thousandths());
+	assert_eq!(100, accept_language.quality("den").as_thousandths());
+}
+
+
+#[test]
+fn test_filter() {
+	// See https://tools.ietf.org/html/rfc4647#section-3.3.1
+	const TAGS: &[&str] = &["de", "de-CH", "de-DE-1996", "de-Latn-DE", "den", "en"];
+	assert_eq!(vec!["de-CH", "de-DE-1996"], accept_language("de-ch, de-de").filter(TAGS));
+	assert_eq!(
+		vec!["de", "de-CH", "de-DE-1996", "de-Latn-DE"],
+		accept_language("de").filter(TAGS)
+	);
+	assert_eq!(
+		vec!["en", "de", "de-CH", "de-DE-1996", "de-Latn-DE"],
+		accept_language("de;q=0.5, en").filter(TAGS)
+	);
+	assert_eq!(vec!["de-CH", "den", "en"], accept_language("*, de;q=0, de-ch").filter(TAGS));
+	assert_eq!(Vec::<&str>::new(), accept_language("fr").filter(TAGS));
+}
+
+
+#[test]
+fn test_lookup() {
+	// See https://tools.ietf.org/html/rfc4647#section-3.4
+	let tags = &["zh", "zh-Hant", "de-CH", "en"];
+	assert_eq!(Some("zh-Hant"), accept_language("zh-Hant-CN-x-private1-private2").lookup(tags));
+	assert_eq!(Some("zh"), accept_language("zh-Hans-CN").lookup(tags));
+	assert_eq!(Some("de-CH"), accept_language("fr, de-ch-1996;q=0.9").lookup(tags));
+	assert_eq!(Some("en"), accept_language("fr;q=0.5, en-GB;q=0.8").lookup(tags));
+	assert_eq!(None, accept_language("*, fr").lookup(tags));
+	assert_eq!(None, accept_language("en;q=0").lookup(tags));
+	assert_eq!(None, AcceptLanguage::default().lookup(tags));
+}
+
+
+#[test]
+fn test_from_fields() {
+	let data = b"GET / HTTP/1.1\r\nAccept-Language: da, en-GB;q=0.8, en;q=0.7\r\n\r\n";
+	let header: RequestHeader = Header::parse(data).unwrap().try_into().unwrap();
+	let key: Data<HeaderFieldKey> = Data::try_from("accept-language").unwrap();
+	let accept_language = AcceptLanguage::from_fields(header.fields().get_all(&key)).unwrap();
+	assert_eq!(Some("en-US"), accept_language.negotiate(&["en-US", "fr"]));
+	assert_eq!(700, accept_language.quality("en-US").as_thousandths());
+	assert_eq!("da, en-gb;q=0.8, en;q=0.7", accept_language.encode().to_string());
+	
+	// Repeated field lines form one list (see RFC 7230, section 3.2.2)
+	let data = b"GET / HTTP/1.1\r\nAccept-Language: da\r\nAccept-Language: de;q=0.5, *;q=0\r\n\r\n";
+	let header: RequestHeader = Header::parse(data).unwrap().try_into().unwrap();
+	let accept_language = AcceptLanguage::from_fields(header.fields().get_all(&key)).unwrap();
+	assert_eq!(Some("de-CH"), accept_language.negotiate(&["en", "de-CH"]));
+	assert_eq!(Quality::ZERO, accept_language.quality("en"));
+	
+	assert_eq!(Quality::ONE, AcceptLanguage::from_fields(None).unwrap().quality("da"));
+}
+
+
+#[test]
+fn test_decode_err() {
+	let decode = |value: &str| {
+		let value: Data<FieldValue> = Data::try_from(value).unwrap();
+		AcceptLanguage::decode(&[&value]).unwrap_err()
+	};
+	assert_eq!(HttpError::ProtocolViolation, decode("en_US"));
+	assert_eq!(HttpError::ProtocolViolation, decode("1de"));
+	assert_eq!(HttpError::ProtocolViolation, decode("de--ch"));
+	assert_eq!(HttpError::ProtocolViolation, decode("abcdefghi"));
+	assert_eq!(HttpError::ProtocolViolation, decode("de;q=1.5"));
+}
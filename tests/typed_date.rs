@@ -0,0 +1,62 @@
+use http_header::{ HttpError, typed::HttpDate };
+use std::time::{ Duration, SystemTime, UNIX_EPOCH };
+
+
+struct Test {
+	date: &'static str,
+	expected: Result<u64, HttpError>
+}
+impl Test {
+	pub fn test(self) {
+		let date = HttpDate::parse(self.date.as_bytes());
+		assert_eq!(self.expected, date.map(|date| date.unix_secs()), "{}", self.date);
+	}
+}
+#[test]
+fn test_parse() {
+	const INVALID: Result<u64, HttpError> = Err(HttpError::ProtocolViolation);
+	
+	// See https://tools.ietf.org/html/rfc7231#section-7.1.1.1
+	Test{ date: "Sun, 06 Nov 1994 08:49:37 GMT", expected: Ok(784_111_777) }.test();
+	Test{ date: "Sunday, 06-Nov-94 08:49:37 GMT", expected: Ok(784_111_777) }.test();
+	Test{ date: "Sun Nov  6 08:49:37 1994", expected: Ok(784_111_777) }.test();
+	
+	Test{ date: "Thu, 01 Jan 1970 00:00:00 GMT", expected: Ok(0) }.test();
+	Test{ date: "Tue, 29 Feb 2000 23:59:59 GMT", expected: Ok(951_868_799) }.test();
+	Test{ date: "Sat, 01 Jan 2000 00:00:00 GMT", expected: Ok(946_684_800) }.test();
+	
+	Test{ date: "Mon, 06 Nov 1994 08:49:37 GMT", expected: INVALID }.test();
+	Test{ date: "Sun, 6 Nov 1994 08:49:37 GMT", expected: INVALID }.test();
+	Test{ date: "Sun, 06 Nov 1994 08:49:37 UTC", expected: INVALID }.test();
+	Test{ date: "Sun, 06 Foo 1994 08:49:37 GMT", expected: INVALID }.test();
+	Test{ date: "Sun, 06 Nov 1994 24:00:00 GMT", expected: INVALID }.test();
+	Test{ date: "Fri, 29 Feb 2019 00:00:00 GMT", expected: INVALID }.test();
+	Test{ date: "Wed, 31 Dec 1969 23:59:59 GMT", expected: INVALID }.test();
+	Test{ date: "Sun, 06 Nov 1994 08:49:37 GMT\u{e4}", expected: Err(HttpError::InvalidEncoding) }
+		.test();
+}
+
+
+#[test]
+fn test_format() {
+	assert_eq!("Sun, 06 Nov 1994 08:49:37 GMT", HttpDate::from_unix_secs(784_111_777).to_string());
+	assert_eq!("Thu, 01 Jan 1970 00:00:00 GMT", HttpDate::from_unix_secs(0).to_string());
+	assert_eq!("Tue, 29 Feb 2000 23:59:59 GMT", HttpDate::from_unix_secs(951_868_799).to_string());
+	let max = HttpDate::from_unix_secs(253_402_300_799);
+	assert_eq!("Fri, 31 Dec 9999 23:59:59 GMT", max.to_string());
+	
+	// Formatting and parsing must be inverse
+	for secs in (0..4_000_000_000u64).step_by(86_399 * 97) {
+		let date = HttpDate::from_unix_secs(secs);
+		assert_eq!(date, HttpDate::parse(date.to_string().as_bytes()).unwrap());
+	}
+}
+
+
+#[test]
+fn test_system_time() {
+	let time = UNIX_EPOCH + Duration::from_millis(784_111_777_999);
+	let date = HttpDate::from(time);
+	assert_eq!(784_111_777, date.unix_secs());
+	assert_eq!(UNIX_EPOCH + Duration::from_secs(784_111_777), SystemTime::from(date));
+}
@@ -0,0 +1,163 @@
+#[macro_use] extern crate http_header;
+use http_header::{
+	Header, HttpError, Method, RequestBuilder, RequestHeader, ResponseBuilder, ResponseHeader,
+	StatusCode, Version,
+	typed::{
+		Connection, ContentLength, ContentType, Date, Host, HttpDate, Location, MediaType, Server,
+		TransferEncoding, TypedHeader, UserAgent
+	}
+};
+use std::{ fmt::Debug, convert::TryInto };
+
+
+/// Parses a request with the given header `fields`
+fn request(fields: &str) -> RequestHeader {
+	let data = format!("GET / HTTP/1.1\r\n{}\r\n", fields);
+	Header::parse(data.as_bytes()).unwrap().try_into().unwrap()
+}
+
+
+struct Test<H: TypedHeader + Debug + PartialEq> {
+	fields: &'static str,
+	expected: Result<Option<H>, HttpError>
+}
+impl<H: TypedHeader + Debug + PartialEq> Test<H> {
+	pub fn test(self) {
+		assert_eq!(self.expected, request(self.fields).typed_get::<H>(), "{}", self.fields);
+	}
+}
+#[test]
+fn test_typed_get() {
+	Test{ fields: "Content-Length: 42\r\n", expected: Ok(Some(ContentLength(42))) }.test();
+	Test{ fields: "Content-Length: 42, 42\r\n", expected: Ok(Some(ContentLength(42))) }.test();
+	Test{ fields: "Host: a\r\n", expected: Ok(None::<ContentLength>) }.test();
+	Test {
+		fields: "Content-Length: 42\r\nContent-Length: 7\r\n",
+		expected: Err::<Option<ContentLength>, _>(HttpError::ConflictingContentLength)
+	}.test();
+	Test {
+		fields: "Content-Length: -1\r\n",
+		expected: Err::<Option<ContentLength>, _>(HttpError::InvalidContentLength)
+	}.test();
+	
+	Test {
+		fields: "Host: Example.com:8080\r\n",
+		expected: Ok(Some(Host{ host: data!("Example.com"), port: Some(8080) }))
+	}.test();
+	Test{ fields: "Host: [::1]\r\n", expected: Ok(Some(Host{ host: data!("[::1]"), port: None })) }
+		.test();
+	Test {
+		fields: "Host: a\r\nHost: b\r\n",
+		expected: Err::<Option<Host>, _>(HttpError::ProtocolViolation)
+	}.test();
+	Test {
+		fields: "Host: user@a/b\r\n",
+		expected: Err::<Option<Host>, _>(HttpError::ProtocolViolation)
+	}.test();
+	
+	Test {
+		fields: "Connection: keep-alive, Upgrade\r\nConnection: ,close\r\n",
+		expected: Ok(Some(Connection(
+			vec![data!("keep-alive"), data!("Upgrade"), data!("close")]
+		)))
+	}.test();
+	Test {
+		fields: "Transfer-Encoding: gzip, chunked\r\n",
+		expected: Ok(Some(TransferEncoding(vec![data!("gzip"), data!("chunked")])))
+	}.test();
+	Test {
+		fields: "Transfer-Encoding: gzip;q=1\r\n",
+		expected: Err::<Option<TransferEncoding>, _>(HttpError::InvalidEncoding)
+	}.test();
+	
+	Test {
+		fields: "Content-Type: text/html; charset=utf-8\r\n",
+		expected: Ok(Some(ContentType(MediaType::new("text", "html").unwrap()
+			.with_param("charset", "utf-8").unwrap())))
+	}.test();
+	Test {
+		fields: "Date: Sun, 06 Nov 1994 08:49:37 GMT\r\n",
+		expected: Ok(Some(Date(HttpDate::from_unix_secs(784_111_777))))
+	}.test();
+	Test {
+		fields: "Date: yesterday\r\n",
+		expected: Err::<Option<Date>, _>(HttpError::ProtocolViolation)
+	}.test();
+	Test {
+		fields: "Location: ../a?b#c\r\n",
+		expected: Ok(Some(Location(data!("../a?b#c"))))
+	}.test();
+	Test {
+		fields: "Location: 1http://a\r\n",
+		expected: Err::<Option<Location>, _>(HttpError::ProtocolViolation)
+	}.test();
+	Test {
+		fields: "User-Agent: curl/7.64.1\r\n",
+		expected: Ok(Some(UserAgent(data!("curl/7.64.1"))))
+	}.test();
+}
+
+
+#[test]
+fn test_helpers() {
+	let connection = request("Connection: Keep-Alive, Upgrade\r\n").typed_get::<Connection>();
+	let connection = connection.unwrap().unwrap();
+	assert!(connection.is_keep_alive());
+	assert!(!connection.is_close());
+	assert!(connection.contains("upgrade"));
+	
+	assert!(TransferEncoding(vec![data!("gzip"), data!("Chunked")]).is_chunked());
+	assert!(!TransferEncoding(vec![data!("chunked"), data!("gzip")]).is_chunked());
+}
+
+
+#[test]
+fn test_typed_insert() {
+	let request = RequestBuilder::new()
+		.method(Method::Post).uri(data!("/upload")).version(Version::Http11)
+		.typed_insert(Host{ host: data!("example.com"), port: Some(8080) })
+		.typed_insert(UserAgent(data!("http_header")))
+		.typed_insert(ContentLength(7))
+		.typed_insert(ContentLength(42))
+		.typed_insert(Connection(vec![data!("keep-alive"), data!("Upgrade")]))
+		.build().unwrap();
+	assert_eq!(
+		concat!(
+			"POST /upload HTTP/1.1\r\n",
+			"Host: example.com:8080\r\n",
+			"User-Agent: http_header\r\n",
+			"Content-Length: 42\r\n",
+			"Connection: keep-alive, Upgrade\r\n",
+			"\r\n"
+		).as_bytes(),
+		request.to_vec().as_slice()
+	);
+	assert_eq!(Ok(Some(ContentLength(42))), request.typed_get());
+	
+	let response = ResponseBuilder::new()
+		.version(Version::Http11).status_code(StatusCode::FOUND)
+		.typed_insert(Date(HttpDate::from_unix_secs(784_111_777)))
+		.typed_insert(Server(data!("test/1.0")))
+		.typed_insert(Location(data!("/login?next=%2F")))
+		.typed_insert(TransferEncoding(vec![data!("chunked")]))
+		.typed_insert(ContentType(MediaType::new("text", "plain").unwrap()))
+		.build().unwrap();
+	assert_eq!(
+		concat!(
+			"HTTP/1.1 302 Found\r\n",
+			"Date: Sun, 06 Nov 1994 08:49:37 GMT\r\n",
+			"Server: test/1.0\r\n",
+			"Location: /login?next=%2F\r\n",
+			"Transfer-Encoding: chunked\r\n",
+			"Content-Type: text/plain\r\n",
+			"\r\n"
+		).as_bytes(),
+		response.to_vec().as_slice()
+	);
+	
+	// Decoding the serialized response must yield the same typed headers
+	let response: ResponseHeader = Header::parse(&response.to_vec()).unwrap().try_into().unwrap();
+	assert_eq!(Ok(Some(Date(HttpDate::from_unix_secs(784_111_777)))), response.typed_get());
+	assert_eq!(Ok(Some(Location(data!("/login?next=%2F")))), response.typed_get());
+	assert_eq!(Ok(None), response.typed_get::<ContentLength>());
+}
@@ -0,0 +1,124 @@
+use http_header::{ HttpError, typed::MediaType };
+
+
+struct Test {
+	media_type: &'static str,
+	type_: &'static str,
+	subtype: &'static str,
+	suffix: Option<&'static str>,
+	params: &'static[(&'static str, &'static str)],
+	serialized: &'static str
+}
+impl Test {
+	pub fn test(self) {
+		let media_type = MediaType::parse(self.media_type.as_bytes()).unwrap();
+		assert_eq!(self.type_, media_type.type_(), "{}", self.media_type);
+		assert_eq!(self.subtype, media_type.subtype(), "{}", self.media_type);
+		assert_eq!(self.suffix, media_type.suffix(), "{}", self.media_type);
+		
+		let params: Vec<(String, String)> = media_type.params().iter()
+			.map(|(name, value)| (name.to_string(), value.to_string())).collect();
+		let expected: Vec<(String, String)> = self.params.iter()
+			.map(|(name, value)| (name.to_string(), value.to_string())).collect();
+		assert_eq!(expected, params, "{}", self.media_type);
+		
+		// Serializing and parsing must be inverse
+		assert_eq!(self.serialized, media_type.to_string());
+		assert_eq!(media_type, MediaType::parse(self.serialized.as_bytes()).unwrap());
+	}
+}
+#[test]
+fn test_parse() {
+	Test {
+		media_type: "application/json", type_: "application", subtype: "json", suffix: None,
+		params: &[], serialized: "application/json"
+	}.test();
+	Test {
+		media_type: "Text/HTML; Charset=\"UTF-8\"", type_: "text", subtype: "html", suffix: None,
+		params: &[("charset", "UTF-8")], serialized: "text/html;charset=UTF-8"
+	}.test();
+	Test {
+		media_type: "application/vnd.api+json ;a=1;;b=\"x;\\\"y\\\\\" ; ", type_: "application",
+		subtype: "vnd.api+json", suffix: Some("json"), params: &[("a", "1"), ("b", "x;\"y\\")],
+		serialized: "application/vnd.api+json;a=1;b=\"x;\\\"y\\\\\""
+	}.test();
+	Test {
+		media_type: "multipart/form-data; boundary=\"\"", type_: "multipart", subtype: "form-data",
+		suffix: None, params: &[("boundary", "")], serialized: "multipart/form-data;boundary=\"\""
+	}.test();
+	Test {
+		media_type: "*/*", type_: "*", subtype: "*", suffix: None,
+		params: &[], serialized: "*/*"
+	}.test();
+}
+
+
+#[test]
+fn test_parse_err() {
+	assert_eq!(Err(HttpError::ProtocolViolation), MediaType::parse(b"text"));
+	assert_eq!(Err(HttpError::ProtocolViolation), MediaType::parse(b"text/"));
+	assert_eq!(Err(HttpError::ProtocolViolation), MediaType::parse(b"te xt/plain"));
+	assert_eq!(Err(HttpError::ProtocolViolation), MediaType::parse(b"text/plain; =b"));
+	assert_eq!(Err(HttpError::ProtocolViolation), MediaType::parse(b"text/plain; charset"));
+	assert_eq!(Err(HttpError::ProtocolViolation), MediaType::parse(b"text/plain; a=\"open"));
+	assert_eq!(Err(HttpError::ProtocolViolation), MediaType::parse(b"text/plain, text/html"));
+	assert_eq!(Err(HttpError::ProtocolViolation), MediaType::parse(b"text/plain; a=b c"));
+	assert_eq!(Err(HttpError::InvalidEncoding), MediaType::parse(b"text/plain; a=\"\x01\""));
+	assert_eq!(Err(HttpError::InvalidEncoding), MediaType::parse(b"text/plain; a=\"\xFC\""));
+}
+
+
+#[test]
+fn test_builder() {
+	let media_type = MediaType::new("Application", "JSON").unwrap()
+		.with_param("Charset", "utf-8").unwrap()
+		.with_param("profile", "a b").unwrap()
+		.with_param("charset", "UTF-8").unwrap();
+	assert_eq!("application/json;charset=UTF-8;profile=\"a b\"", media_type.to_string());
+	assert_eq!("application/json", media_type.essence());
+	assert_eq!(Some("UTF-8"), media_type.param("CHARSET").map(|v| v.to_string()).as_deref());
+	
+	assert_eq!(Err(HttpError::InvalidEncoding), MediaType::new("text", "pl/ain"));
+	assert_eq!(
+		Err(HttpError::InvalidEncoding),
+		MediaType::new("text", "plain").unwrap().with_param("a", "\n")
+	);
+	assert_eq!(
+		Err(HttpError::InvalidEncoding),
+		MediaType::new("text", "plain").unwrap().with_param("a", "ü")
+	);
+}
+
+
+#[test]
+fn test_is() {
+	let media_type = MediaType::parse(b"application/vnd.api+json; charset=utf-8").unwrap();
+	assert!(media_type.is("json"));
+	assert!(media_type.is("JSON"));
+	assert!(media_type.is("vnd.api+json"));
+	assert!(media_type.is("application/*"));
+	assert!(media_type.is("*/*"));
+	assert!(media_type.is("application/vnd.api+json"));
+	assert!(!media_type.is("application/json"));
+	assert!(!media_type.is("text/*"));
+	assert!(!media_type.is("xml"));
+	assert!(!media_type.is(""));
+	assert!(!media_type.is_wildcard());
+}
+
+
+#[test]
+fn test_matches() {
+	let media_type = MediaType::parse(b"text/html; charset=utf-8; level=1").unwrap();
+	let range = |range: &str| MediaType::parse(range.as_bytes()).unwrap();
+	assert!(media_type.matches(&range("*/*")));
+	assert!(media_type.matches(&range("text/*")));
+	assert!(media_type.matches(&range("text/html")));
+	assert!(media_type.matches(&range("TEXT/HTML;charset=UTF-8")));
+	assert!(media_type.matches(&range("text/html;level=1;charset=utf-8")));
+	assert!(!media_type.matches(&range("text/html;level=2")));
+	assert!(!media_type.matches(&range("text/html;format=flowed")));
+	assert!(!media_type.matches(&range("text/plain")));
+	assert!(!media_type.matches(&range("image/*")));
+	assert!(range("text/*").is_wildcard());
+}
@@ -0,0 +1,88 @@
+#[macro_use] extern crate http_header;
+use http_header::{
+	HttpError,
+	data::{ Data, encodings::Uri },
+	uri::{ self, ParsedUri }
+};
+use std::convert::TryFrom;
+
+
+struct Test {
+	uri: &'static str,
+	expected: &'static str
+}
+impl Test {
+	pub fn test(self) {
+		let normalized = uri::normalize(&data!(self.uri)).unwrap();
+		assert_eq!(self.expected, normalized.to_string(), "{}", self.uri);
+		
+		// Normalization must be idempotent
+		let renormalized = uri::normalize(&normalized).unwrap();
+		assert_eq!(normalized, renormalized);
+	}
+}
+#[test]
+fn test() {
+	Test{ uri: "HTTP://Example.com:80/%7efoo", expected: "http://example.com/~foo" }.test();
+	Test{ uri: "eXAMPLE://a/./b/../b/%63/%7bfoo%7d", expected: "example://a/b/c/%7Bfoo%7D" }.test();
+	Test{ uri: "http://example.com", expected: "http://example.com/" }.test();
+	Test{ uri: "http://example.com:/", expected: "http://example.com/" }.test();
+	Test{ uri: "https://example.com:443/a", expected: "https://example.com/a" }.test();
+	Test{ uri: "https://example.com:80/a", expected: "https://example.com:80/a" }.test();
+	Test{ uri: "http://%7EUser@EXAMPLE.com/", expected: "http://~User@example.com/" }.test();
+	Test {
+		uri: "http://example.com/a%2fb?%2d=%3d#%41",
+		expected: "http://example.com/a%2Fb?-=%3D#A"
+	}.test();
+	Test{ uri: "http://%41.com/", expected: "http://a.com/" }.test();
+	Test{ uri: "http://K%c3%b6LN.de/", expected: "http://k%C3%B6ln.de/" }.test();
+	Test{ uri: "http://[2001:DB8::7]/", expected: "http://[2001:db8::7]/" }.test();
+	Test{ uri: "mailto:John.Doe@Example.com", expected: "mailto:John.Doe@Example.com" }.test();
+	Test{ uri: "../a/./b", expected: "../a/./b" }.test();
+}
+
+
+struct TestEquivalent {
+	a: &'static str,
+	b: &'static str,
+	expected: bool
+}
+impl TestEquivalent {
+	pub fn test(self) {
+		let (a, b): (Data<Uri>, Data<Uri>) = (data!(self.a), data!(self.b));
+		assert_eq!(self.expected, uri::is_equivalent(&a, &b).unwrap(), "{} <> {}", self.a, self.b);
+		
+		let (a, b) = (ParsedUri::try_from(&a).unwrap(), ParsedUri::try_from(&b).unwrap());
+		assert_eq!(self.expected, a.is_equivalent(&b));
+	}
+}
+#[test]
+fn test_equivalent() {
+	TestEquivalent {
+		a: "HTTP://Example.com:80/%7efoo", b: "http://example.com/~foo",
+		expected: true
+	}.test();
+	TestEquivalent{ a: "http://%41.com/", b: "http://a.com/", expected: true }.test();
+	TestEquivalent {
+		a: "http://example.com/a/../b", b: "http://example.com/b",
+		expected: true
+	}.test();
+	TestEquivalent {
+		a: "http://example.com/~foo", b: "http://example.com/~Foo",
+		expected: false
+	}.test();
+	TestEquivalent {
+		a: "http://example.com:8080/", b: "http://example.com/",
+		expected: false
+	}.test();
+	TestEquivalent {
+		a: "http://example.com/?a", b: "http://example.com/?A",
+		expected: false
+	}.test();
+}
+
+
+#[test]
+fn test_err() {
+	assert_eq!(HttpError::ProtocolViolation, uri::normalize(&data!("1http://a/")).unwrap_err());
+}
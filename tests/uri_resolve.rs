@@ -0,0 +1,101 @@
+#[macro_use] extern crate http_header;
+use http_header::{
+	HttpError,
+	data::{ Data, encodings::Uri },
+	uri::{ self, ParsedUri }
+};
+use std::convert::TryFrom;
+
+
+const BASE: &str = "http://a/b/c/d;p?q";
+
+
+struct Test {
+	reference: &'static str,
+	expected: &'static str
+}
+impl Test {
+	pub fn test(self) {
+		let resolved = uri::resolve(&data!(BASE), &data!(self.reference)).unwrap();
+		assert_eq!(self.expected, resolved.to_string(), "{}", self.reference);
+	}
+}
+#[test]
+fn test_normal() {
+	// See https://tools.ietf.org/html/rfc3986#section-5.4.1
+	Test{ reference: "g:h", expected: "g:h" }.test();
+	Test{ reference: "g", expected: "http://a/b/c/g" }.test();
+	Test{ reference: "./g", expected: "http://a/b/c/g" }.test();
+	Test{ reference: "g/", expected: "http://a/b/c/g/" }.test();
+	Test{ reference: "/g", expected: "http://a/g" }.test();
+	Test{ reference: "//g", expected: "http://g" }.test();
+	Test{ reference: "?y", expected: "http://a/b/c/d;p?y" }.test();
+	Test{ reference: "g?y", expected: "http://a/b/c/g?y" }.test();
+	Test{ reference: "#s", expected: "http://a/b/c/d;p?q#s" }.test();
+	Test{ reference: "g#s", expected: "http://a/b/c/g#s" }.test();
+	Test{ reference: "g?y#s", expected: "http://a/b/c/g?y#s" }.test();
+	Test{ reference: ";x", expected: "http://a/b/c/;x" }.test();
+	Test{ reference: "g;x", expected: "http://a/b/c/g;x" }.test();
+	Test{ reference: "g;x?y#s", expected: "http://a/b/c/g;x?y#s" }.test();
+	Test{ reference: "", expected: "http://a/b/c/d;p?q" }.test();
+	Test{ reference: ".", expected: "http://a/b/c/" }.test();
+	Test{ reference: "./", expected: "http://a/b/c/" }.test();
+	Test{ reference: "..", expected: "http://a/b/" }.test();
+	Test{ reference: "../", expected: "http://a/b/" }.test();
+	Test{ reference: "../g", expected: "http://a/b/g" }.test();
+	Test{ reference: "../..", expected: "http://a/" }.test();
+	Test{ reference: "../../", expected: "http://a/" }.test();
+	Test{ reference: "../../g", expected: "http://a/g" }.test();
+}
+#[test]
+fn test_abnormal() {
+	// See https://tools.ietf.org/html/rfc3986#section-5.4.2
+	Test{ reference: "../../../g", expected: "http://a/g" }.test();
+	Test{ reference: "../../../../g", expected: "http://a/g" }.test();
+	Test{ reference: "/./g", expected: "http://a/g" }.test();
+	Test{ reference: "/../g", expected: "http://a/g" }.test();
+	Test{ reference: "g.", expected: "http://a/b/c/g." }.test();
+	Test{ reference: ".g", expected: "http://a/b/c/.g" }.test();
+	Test{ reference: "g..", expected: "http://a/b/c/g.." }.test();
+	Test{ reference: "..g", expected: "http://a/b/c/..g" }.test();
+	Test{ reference: "./../g", expected: "http://a/b/g" }.test();
+	Test{ reference: "./g/.", expected: "http://a/b/c/g/" }.test();
+	Test{ reference: "g/./h", expected: "http://a/b/c/g/h" }.test();
+	Test{ reference: "g/../h", expected: "http://a/b/c/h" }.test();
+	Test{ reference: "g;x=1/./y", expected: "http://a/b/c/g;x=1/y" }.test();
+	Test{ reference: "g;x=1/../y", expected: "http://a/b/c/y" }.test();
+	Test{ reference: "g?y/./x", expected: "http://a/b/c/g?y/./x" }.test();
+	Test{ reference: "g?y/../x", expected: "http://a/b/c/g?y/../x" }.test();
+	Test{ reference: "g#s/./x", expected: "http://a/b/c/g#s/./x" }.test();
+	Test{ reference: "g#s/../x", expected: "http://a/b/c/g#s/../x" }.test();
+	Test{ reference: "http:g", expected: "http:g" }.test();
+}
+
+
+#[test]
+fn test_remove_dot_segments() {
+	assert_eq!(b"/a/g".as_ref(), uri::remove_dot_segments(b"/a/b/c/./../../g").as_slice());
+	assert_eq!(b"mid/6".as_ref(), uri::remove_dot_segments(b"mid/content=5/../6").as_slice());
+	assert_eq!(b"".as_ref(), uri::remove_dot_segments(b"../..").as_slice());
+}
+
+
+#[test]
+fn test_parsed() {
+	let base = ParsedUri::try_from("http://user@example.com:8080/x/y/z?q#f").unwrap();
+	let reference = ParsedUri::try_from("../a?b").unwrap();
+	let resolved = base.resolve(&reference).unwrap();
+	assert_eq!("http://user@example.com:8080/x/a?b", resolved.to_string());
+	assert_eq!(Some(8080), resolved.port());
+	
+	let base = ParsedUri::try_from("http://example.com").unwrap();
+	let reference = ParsedUri::try_from("a").unwrap();
+	assert_eq!("http://example.com/a", base.resolve(&reference).unwrap().to_string());
+}
+
+
+#[test]
+fn test_err() {
+	let base: Data<Uri> = data!("/relative/base");
+	assert_eq!(HttpError::ApiMisuse, uri::resolve(&base, &data!("g")).unwrap_err());
+}